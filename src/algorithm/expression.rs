@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+
+use crate::datastruct::number::Number;
+use crate::datastruct::stack::stack::Stack;
+
+// every position is the character index of the token in the source expression
+#[derive(Debug, PartialEq)]
+pub enum ExpressionError {
+    EmptyExpression,
+    InvalidCharacter(char, usize),
+    InvalidNumber(usize),
+    UnknownVariable(String, usize),
+    UnexpectedOperand(usize),
+    MissingOperand(usize),
+    UnmatchedParenthesis(usize),
+    DivisionByZero(usize),
+    Overflow(usize),
+    InvalidOperation(usize),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum OperandError {
+    DivisionByZero,
+    Overflow,
+    InvalidOperation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+    Neg,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Number(String),
+    Variable(String),
+    Operator(Operator),
+    LeftParenthesis,
+    RightParenthesis,
+}
+
+pub trait Operand: Sized + Clone + PartialEq {
+    fn parse(literal: &str) -> Option<Self>;
+    fn apply(operator: Operator, left: Self, right: Self) -> Result<Self, OperandError>;
+    fn negate(self) -> Result<Self, OperandError>;
+}
+
+impl Operator {
+    pub fn precedence(&self) -> usize {
+        match self {
+            Operator::Add | Operator::Sub => 1,
+            Operator::Mul | Operator::Div | Operator::Rem => 2,
+            Operator::Neg => 3,
+            Operator::Pow => 4,
+        }
+    }
+    pub fn is_right_associative(&self) -> bool {
+        matches!(self, Operator::Pow | Operator::Neg)
+    }
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '+' => Some(Operator::Add),
+            '-' => Some(Operator::Sub),
+            '*' => Some(Operator::Mul),
+            '/' => Some(Operator::Div),
+            '%' => Some(Operator::Rem),
+            '^' => Some(Operator::Pow),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Number(literal) | Token::Variable(literal) => write!(f, "{}", literal),
+            Token::Operator(Operator::Add) => write!(f, "+"),
+            Token::Operator(Operator::Sub) => write!(f, "-"),
+            Token::Operator(Operator::Mul) => write!(f, "*"),
+            Token::Operator(Operator::Div) => write!(f, "/"),
+            Token::Operator(Operator::Rem) => write!(f, "%"),
+            Token::Operator(Operator::Pow) => write!(f, "^"),
+            Token::Operator(Operator::Neg) => write!(f, "neg"),
+            Token::LeftParenthesis => write!(f, "("),
+            Token::RightParenthesis => write!(f, ")"),
+        }
+    }
+}
+
+pub fn tokenize(expression: &str) -> Result<Vec<(Token, usize)>, ExpressionError> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        let start = index;
+        if c.is_whitespace() {
+            index += 1;
+        } else if c.is_ascii_digit() || c == '.' {
+            while index < chars.len() && (chars[index].is_ascii_digit() || chars[index] == '.') {
+                index += 1;
+            }
+            tokens.push((Token::Number(chars[start..index].iter().collect()), start));
+        } else if c.is_alphabetic() || c == '_' {
+            while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+                index += 1;
+            }
+            tokens.push((Token::Variable(chars[start..index].iter().collect()), start));
+        } else if c == '(' {
+            tokens.push((Token::LeftParenthesis, start));
+            index += 1;
+        } else if c == ')' {
+            tokens.push((Token::RightParenthesis, start));
+            index += 1;
+        } else if let Some(operator) = Operator::from_char(c) {
+            tokens.push((Token::Operator(operator), start));
+            index += 1;
+        } else {
+            return Err(ExpressionError::InvalidCharacter(c, start));
+        }
+    }
+    Ok(tokens)
+}
+
+pub fn infix_to_postfix(expression: &str) -> Result<Vec<(Token, usize)>, ExpressionError> {
+    let tokens = tokenize(expression)?;
+    if tokens.is_empty() {
+        return Err(ExpressionError::EmptyExpression);
+    }
+    let mut output = Vec::new();
+    let mut stack: Stack<(Token, usize)> = Stack::new();
+    let mut expect_operand = true;
+    for (token, position) in tokens {
+        match token {
+            Token::Number(_) | Token::Variable(_) => {
+                if !expect_operand {
+                    return Err(ExpressionError::UnexpectedOperand(position));
+                }
+                output.push((token, position));
+                expect_operand = false;
+            }
+            Token::LeftParenthesis => {
+                if !expect_operand {
+                    return Err(ExpressionError::UnexpectedOperand(position));
+                }
+                stack.push((token, position));
+            }
+            Token::RightParenthesis => {
+                if expect_operand {
+                    return Err(ExpressionError::MissingOperand(position));
+                }
+                loop {
+                    match stack.pop() {
+                        Some((Token::LeftParenthesis, _)) => break,
+                        Some(operator) => output.push(operator),
+                        None => return Err(ExpressionError::UnmatchedParenthesis(position)),
+                    }
+                }
+            }
+            Token::Operator(operator) => {
+                if expect_operand {
+                    // a prefix sign has no left operand so nothing is popped for it
+                    match operator {
+                        Operator::Sub => stack.push((Token::Operator(Operator::Neg), position)),
+                        Operator::Add => (),
+                        _ => return Err(ExpressionError::MissingOperand(position)),
+                    }
+                    continue;
+                }
+                while let Some((Token::Operator(top), _)) = stack.peek() {
+                    if top.precedence() > operator.precedence()
+                        || (top.precedence() == operator.precedence() && !operator.is_right_associative()) {
+                        if let Some(top) = stack.pop() {
+                            output.push(top);
+                        }
+                    } else {
+                        break;
+                    }
+                }
+                stack.push((Token::Operator(operator), position));
+                expect_operand = true;
+            }
+        }
+    }
+    if expect_operand {
+        return Err(ExpressionError::MissingOperand(expression.chars().count()));
+    }
+    while let Some((token, position)) = stack.pop() {
+        if token == Token::LeftParenthesis {
+            return Err(ExpressionError::UnmatchedParenthesis(position));
+        }
+        output.push((token, position));
+    }
+    Ok(output)
+}
+
+pub fn evaluate_postfix<T: Operand>(postfix: &[(Token, usize)], variables: &HashMap<String, T>) -> Result<T, ExpressionError> {
+    let map_error = |error: OperandError, position: usize| match error {
+        OperandError::DivisionByZero => ExpressionError::DivisionByZero(position),
+        OperandError::Overflow => ExpressionError::Overflow(position),
+        OperandError::InvalidOperation => ExpressionError::InvalidOperation(position),
+    };
+    let mut stack: Stack<T> = Stack::new();
+    for (token, position) in postfix.iter() {
+        let position = *position;
+        match token {
+            Token::Number(literal) => match T::parse(literal) {
+                Some(n) => stack.push(n),
+                None => return Err(ExpressionError::InvalidNumber(position)),
+            },
+            Token::Variable(name) => match variables.get(name) {
+                Some(n) => stack.push(n.clone()),
+                None => return Err(ExpressionError::UnknownVariable(name.clone(), position)),
+            },
+            Token::Operator(Operator::Neg) => match stack.pop() {
+                Some(n) => stack.push(n.negate().map_err(|e| map_error(e, position))?),
+                None => return Err(ExpressionError::MissingOperand(position)),
+            },
+            Token::Operator(operator) => match (stack.pop(), stack.pop()) {
+                (Some(right), Some(left)) => stack.push(T::apply(*operator, left, right).map_err(|e| map_error(e, position))?),
+                _ => return Err(ExpressionError::MissingOperand(position)),
+            },
+            Token::LeftParenthesis | Token::RightParenthesis => return Err(ExpressionError::UnmatchedParenthesis(position)),
+        }
+    }
+    match (stack.pop(), stack.len) {
+        (Some(n), 0) => Ok(n),
+        (Some(_), _) => Err(ExpressionError::UnexpectedOperand(postfix.last().map(|(_, p)| *p).unwrap_or(0))),
+        (None, _) => Err(ExpressionError::EmptyExpression),
+    }
+}
+
+pub fn evaluate<T: Operand>(expression: &str, variables: &HashMap<String, T>) -> Result<T, ExpressionError> {
+    evaluate_postfix(&infix_to_postfix(expression)?, variables)
+}
+
+impl Operand for i64 {
+    fn parse(literal: &str) -> Option<Self> {
+        literal.parse().ok()
+    }
+    fn apply(operator: Operator, left: Self, right: Self) -> Result<Self, OperandError> {
+        let result = match operator {
+            Operator::Add => left.checked_add(right),
+            Operator::Sub => left.checked_sub(right),
+            Operator::Mul => left.checked_mul(right),
+            Operator::Div | Operator::Rem if right == 0 => return Err(OperandError::DivisionByZero),
+            Operator::Div => left.checked_div(right),
+            Operator::Rem => left.checked_rem(right),
+            Operator::Pow => match u32::try_from(right) {
+                Ok(exp) => left.checked_pow(exp),
+                Err(_) if right < 0 => return Err(OperandError::InvalidOperation),
+                Err(_) => None,
+            },
+            Operator::Neg => return Err(OperandError::InvalidOperation),
+        };
+        result.ok_or(OperandError::Overflow)
+    }
+    fn negate(self) -> Result<Self, OperandError> {
+        self.checked_neg().ok_or(OperandError::Overflow)
+    }
+}
+
+impl Operand for f64 {
+    fn parse(literal: &str) -> Option<Self> {
+        literal.parse().ok()
+    }
+    fn apply(operator: Operator, left: Self, right: Self) -> Result<Self, OperandError> {
+        match operator {
+            Operator::Add => Ok(left + right),
+            Operator::Sub => Ok(left - right),
+            Operator::Mul => Ok(left * right),
+            Operator::Div | Operator::Rem if right == 0.0 => Err(OperandError::DivisionByZero),
+            Operator::Div => Ok(left / right),
+            Operator::Rem => Ok(left % right),
+            Operator::Pow => Ok(left.powf(right)),
+            Operator::Neg => Err(OperandError::InvalidOperation),
+        }
+    }
+    fn negate(self) -> Result<Self, OperandError> {
+        Ok(-self)
+    }
+}
+
+const NUMBER_LITERAL_BITS: usize = 128;
+// larger results are reported as an overflow, the bit string arithmetic gets too slow beyond this
+const NUMBER_MAX_BITS: usize = 1024;
+
+// extend both operands so that a result of the given significant bits cannot wrap
+fn widen(left: &mut Number, right: &mut Number, bits: usize) -> Result<(), OperandError> {
+    if bits > NUMBER_MAX_BITS {
+        return Err(OperandError::Overflow);
+    }
+    let bits = usize::max(bits.div_ceil(64) * 64, usize::max(left.bits(), right.bits()));
+    left.extend(bits);
+    right.extend(bits);
+    Ok(())
+}
+
+// literals get enough bits for their digits, results are widened before they could wrap
+impl Operand for Number {
+    fn parse(literal: &str) -> Option<Self> {
+        let bits = usize::max(NUMBER_LITERAL_BITS, (literal.len() * 4 + 1).div_ceil(64) * 64);
+        Number::from_dec(literal, bits).ok()
+    }
+    fn apply(operator: Operator, mut left: Self, mut right: Self) -> Result<Self, OperandError> {
+        match operator {
+            Operator::Add | Operator::Sub => {
+                let bits = usize::max(left.significant_bits(), right.significant_bits()) + 1;
+                widen(&mut left, &mut right, bits)?;
+                Ok(if let Operator::Add = operator { left + right } else { left - right })
+            }
+            Operator::Mul => {
+                let bits = left.significant_bits() + right.significant_bits();
+                widen(&mut left, &mut right, bits)?;
+                Ok(left * right)
+            }
+            Operator::Pow => {
+                let mut exp: u32 = right.dec().parse().map_err(|_| OperandError::InvalidOperation)?;
+                // 0, 1 and -1 stay that small for any exponent, the bit estimate would reject them
+                let small = match left.dec().as_str() {
+                    "0" if exp > 0 => Some("0"),
+                    "-1" if exp % 2 == 1 => Some("-1"),
+                    "0" | "1" | "-1" => Some("1"),
+                    _ => None,
+                };
+                if let Some(value) = small {
+                    return Number::from_dec(value, NUMBER_LITERAL_BITS).map_err(|_| OperandError::InvalidOperation);
+                }
+                let bits = left.significant_bits().saturating_mul(usize::max(exp as usize, 1));
+                let mut result = Number::from_dec("1", NUMBER_LITERAL_BITS).map_err(|_| OperandError::InvalidOperation)?;
+                widen(&mut left, &mut result, bits)?;
+                let mut base = left;
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        result *= base.clone();
+                    }
+                    exp >>= 1;
+                    if exp > 0 {
+                        base = base.clone() * base;
+                    }
+                }
+                Ok(result)
+            }
+            Operator::Div | Operator::Rem | Operator::Neg => Err(OperandError::InvalidOperation),
+        }
+    }
+    fn negate(mut self) -> Result<Self, OperandError> {
+        // the most negative value needs one more bit once negated
+        let bits = self.significant_bits() + 1;
+        if bits > self.bits() {
+            self.extend(bits.div_ceil(64) * 64);
+        }
+        Ok(-self)
+    }
+}
@@ -1,6 +1,7 @@
 pub mod sort;
 pub mod string;
 pub mod bit;
+pub mod expression;
 
 
 #[cfg(test)]
@@ -46,6 +47,18 @@ mod test_sort {
         assert_eq!(test, standard);
     }
     #[test]
+    fn test_heap_sort_by() {
+        use crate::datastruct::heap::min_max_heap::MinMaxHeap;
+        use crate::datastruct::heap::dary_heap::DaryHeap;
+        let (mut test, mut standard) = gen_ranom_vector();
+        standard.sort();
+        let mut test2 = test.clone();
+        heap_sort_by(&mut test, MinMaxHeap::new());
+        assert_eq!(test, standard);
+        heap_sort_by(&mut test2, DaryHeap::new(4));
+        assert_eq!(test2, standard);
+    }
+    #[test]
     fn test_bucket_sort() {
         let mut v1 = Vec::new();
         let mut v2 = Vec::new();
@@ -150,3 +163,87 @@ mod test_bit{
         }
     }
 }
+
+#[cfg(test)]
+mod test_expression {
+    use super::expression::*;
+    use crate::datastruct::number::Number;
+    use std::collections::HashMap;
+    fn postfix_string(expression: &str) -> String {
+        infix_to_postfix(expression).unwrap().iter().map(|(token, _)| token.to_string()).collect::<Vec<String>>().join(" ")
+    }
+    #[test]
+    fn test_infix_to_postfix() {
+        assert_eq!(postfix_string("1 + 2 * 3"), "1 2 3 * +");
+        assert_eq!(postfix_string("(1 + 2) * 3"), "1 2 + 3 *");
+        assert_eq!(postfix_string("1 - 2 - 3"), "1 2 - 3 -");
+        assert_eq!(postfix_string("2 ^ 3 ^ 2"), "2 3 2 ^ ^");
+        assert_eq!(postfix_string("-2 ^ 2"), "2 2 ^ neg");
+        assert_eq!(postfix_string("2 ^ -x * y"), "2 x neg ^ y *");
+        assert_eq!(postfix_string("--a + +b"), "a neg neg b +");
+        assert_eq!(postfix_string("a % b / c"), "a b % c /");
+    }
+    #[test]
+    fn test_evaluate_i64() {
+        let variables: HashMap<String, i64> = HashMap::from([("x".to_string(), 5), ("y_2".to_string(), -3)]);
+        assert_eq!(evaluate("1 + 2 * 3", &variables), Ok(7));
+        assert_eq!(evaluate("(1 + 2) * 3", &variables), Ok(9));
+        assert_eq!(evaluate("10 - 4 - 3", &variables), Ok(3));
+        assert_eq!(evaluate("2 ^ 3 ^ 2", &variables), Ok(512));
+        assert_eq!(evaluate("-2 ^ 2", &variables), Ok(-4));
+        assert_eq!(evaluate("(-2) ^ 2", &variables), Ok(4));
+        assert_eq!(evaluate("x * y_2 + 17 % 5", &variables), Ok(-13));
+        assert_eq!(evaluate("-(x - -y_2)", &variables), Ok(-2));
+        assert_eq!(evaluate("7 / 2", &variables), Ok(3));
+    }
+    #[test]
+    fn test_evaluate_f64() {
+        let variables: HashMap<String, f64> = HashMap::from([("pi".to_string(), std::f64::consts::PI)]);
+        assert_eq!(evaluate("7 / 2", &variables), Ok(3.5));
+        assert_eq!(evaluate("0.5 * 4 ^ 0.5", &variables), Ok(1.0));
+        assert_eq!(evaluate("2 * pi", &variables), Ok(2.0 * std::f64::consts::PI));
+        assert_eq!(evaluate("1.5.2", &variables), Err(ExpressionError::InvalidNumber(0)));
+    }
+    #[test]
+    fn test_evaluate_number() {
+        let variables: HashMap<String, Number> = HashMap::from([("big".to_string(), Number::from_dec("123456789012345678901234567890", 256).unwrap())]);
+        assert_eq!(evaluate("big * 10 - -1", &variables).unwrap().dec(), "1234567890123456789012345678901");
+        assert_eq!(evaluate("2 ^ 100", &variables).unwrap().dec(), "1267650600228229401496703205376");
+        assert_eq!(evaluate("-(3 + 4) * 5", &variables).unwrap().dec(), "-35");
+        assert_eq!(evaluate("big / 2", &variables), Err(ExpressionError::InvalidOperation(4)));
+    }
+    #[test]
+    fn test_evaluate_number_widening() {
+        let variables: HashMap<String, Number> = HashMap::new();
+        assert_eq!(evaluate("2 ^ 128", &variables).unwrap().dec(), "340282366920938463463374607431768211456");
+        assert_eq!(evaluate("12345678901234567890 * 98765432109876543210", &variables).unwrap().dec(), "1219326311370217952237463801111263526900");
+        assert_eq!(evaluate("-99999999999999999999 * 99999999999999999999", &variables).unwrap().dec(), "-9999999999999999999800000000000000000001");
+        assert_eq!(evaluate("2 ^ 127 + 2 ^ 127", &variables).unwrap().dec(), "340282366920938463463374607431768211456");
+        assert_eq!(evaluate("-(0 - 2 ^ 127)", &variables).unwrap().dec(), "170141183460469231731687303715884105728");
+        assert_eq!(evaluate("2 ^ 5000", &variables), Err(ExpressionError::Overflow(2)));
+        assert_eq!(evaluate("1 ^ 2000", &variables).unwrap().dec(), "1");
+        assert_eq!(evaluate("(-1) ^ 2001", &variables).unwrap().dec(), "-1");
+        assert_eq!(evaluate("(-1) ^ 2000", &variables).unwrap().dec(), "1");
+        assert_eq!(evaluate("0 ^ 5000", &variables).unwrap().dec(), "0");
+        assert_eq!(evaluate("0 ^ 0", &variables).unwrap().dec(), "1");
+        assert_eq!(evaluate("(-2) ^ 5000", &variables), Err(ExpressionError::Overflow(5)));
+    }
+    #[test]
+    fn test_errors() {
+        let variables: HashMap<String, i64> = HashMap::from([("x".to_string(), 1)]);
+        assert_eq!(evaluate("", &variables), Err(ExpressionError::EmptyExpression));
+        assert_eq!(evaluate("1 + $", &variables), Err(ExpressionError::InvalidCharacter('$', 4)));
+        assert_eq!(evaluate("1 + y", &variables), Err(ExpressionError::UnknownVariable("y".to_string(), 4)));
+        assert_eq!(evaluate("1 2", &variables), Err(ExpressionError::UnexpectedOperand(2)));
+        assert_eq!(evaluate("x (1)", &variables), Err(ExpressionError::UnexpectedOperand(2)));
+        assert_eq!(evaluate("1 +", &variables), Err(ExpressionError::MissingOperand(3)));
+        assert_eq!(evaluate("* 1", &variables), Err(ExpressionError::MissingOperand(0)));
+        assert_eq!(evaluate("(1 + )", &variables), Err(ExpressionError::MissingOperand(5)));
+        assert_eq!(evaluate("(1 + 2", &variables), Err(ExpressionError::UnmatchedParenthesis(0)));
+        assert_eq!(evaluate("1 + 2)", &variables), Err(ExpressionError::UnmatchedParenthesis(5)));
+        assert_eq!(evaluate("4 / (x - 1)", &variables), Err(ExpressionError::DivisionByZero(2)));
+        assert_eq!(evaluate("2 ^ 70", &variables), Err(ExpressionError::Overflow(2)));
+        assert_eq!(evaluate("2 ^ -1", &variables), Err(ExpressionError::InvalidOperation(2)));
+        assert_eq!(evaluate("99999999999999999999", &variables), Err(ExpressionError::InvalidNumber(0)));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::datastruct::heap::PriorityQueue;
+
 pub fn bubble_sort<T: PartialOrd>(data: &mut Vec<T>) {
     for i in 0..data.len() - 1 {
         for j in i + 1..data.len() {
@@ -32,7 +34,10 @@ pub fn insert_sort<T: PartialOrd>(data: &mut Vec<T>) {
 
 pub fn heap_sort<T: PartialOrd>(data: &mut Vec<T>) {
     use crate::datastruct::heap::heap::Heap;
-    let mut heap = Heap::new();
+    heap_sort_by(data, Heap::new());
+}
+
+pub fn heap_sort_by<T: PartialOrd, Q: PriorityQueue<T>>(data: &mut Vec<T>, mut heap: Q) {
     while let Some(n) = data.pop() {
         heap.push(n);
     } 
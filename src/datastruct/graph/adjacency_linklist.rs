@@ -1,20 +1,21 @@
 use std::collections::{HashMap, HashSet};
-use crate::datastruct::{graph::{GraphTrait, GraphType}, heap};
+use crate::datastruct::graph::{GraphTrait, GraphType, Weight};
 
 #[derive(Debug, Clone)]
-pub struct GraphLinkList {
+pub struct GraphLinkList<W: Weight = isize> {
     pub to: usize,
-    pub weight: isize,
-    pub next: Option<Box<GraphLinkList>>,
+    pub weight: W,
+    pub next: Option<Box<GraphLinkList<W>>>,
 }
 
 #[derive(Debug, Clone)]
-pub struct GraphAdjacencyLinkList {
-    pub vertex: HashMap<usize, Option<Box<GraphLinkList>>>,
+pub struct GraphAdjacencyLinkList<W: Weight = isize, V = ()> {
+    pub vertex: HashMap<usize, Option<Box<GraphLinkList<W>>>>,
+    pub payload: HashMap<usize, V>,
     pub graph_type: GraphType,
 }
 
-impl GraphTrait for GraphAdjacencyLinkList {
+impl<W: Weight, V: Clone> GraphTrait<W, V> for GraphAdjacencyLinkList<W, V> {
     fn print(&self) {
         let mut edge_map = HashMap::new();
         let mut vertex = Vec::new();
@@ -35,7 +36,7 @@ impl GraphTrait for GraphAdjacencyLinkList {
             for to in vertex.iter() {
                 match edge_map.get(from) {
                     Some(edge) => match edge.get(&to) {
-                        Some(weight) => print!("{}\t", weight),
+                        Some(weight) => print!("{:?}\t", weight),
                         None => print!("nan\t"),
                     }
                     None => panic!("error"),
@@ -44,7 +45,7 @@ impl GraphTrait for GraphAdjacencyLinkList {
             println!("");
         }
     }
-    fn convert_to_vector(&self) -> Vec<Vec<Option<isize>>> {
+    fn convert_to_vector(&self) -> Vec<Vec<Option<W>>> {
         let mut edge_map = HashMap::new();
         let mut vertex = Vec::new();
         for (from, head) in self.vertex.iter() {
@@ -83,13 +84,24 @@ impl GraphTrait for GraphAdjacencyLinkList {
             return false;
         }
         self.vertex.remove(&id);
+        self.payload.remove(&id);
         for head in self.vertex.values_mut() {
             let mut flag = false;
             *head = Self::delete_by_sort(head.take(), id, &mut flag);
         }
         true
     }
-    fn add_edge(&mut self, (from, to, weight): (usize, usize, isize)) -> bool {
+    fn set_payload(&mut self, id: usize, payload: V) -> bool {
+        if !self.vertex.contains_key(&id) {
+            return false;
+        }
+        self.payload.insert(id, payload);
+        true
+    }
+    fn payload(&self, id: usize) -> Option<&V> {
+        self.payload.get(&id)
+    }
+    fn add_edge(&mut self, (from, to, weight): (usize, usize, W)) -> bool {
         self.add_vertex(from);
         self.add_vertex(to);
         match self.vertex.get_mut(&from) {
@@ -132,7 +144,7 @@ impl GraphTrait for GraphAdjacencyLinkList {
         };
         flag
     }
-    fn update_edge(&mut self, (from, to, weight): (usize, usize, isize)) -> bool {
+    fn update_edge(&mut self, (from, to, weight): (usize, usize, W)) -> bool {
         let mut flag = match self.vertex.get_mut(&from) {
             Some(head) => {
                 let mut flag = false;
@@ -212,18 +224,43 @@ impl GraphTrait for GraphAdjacencyLinkList {
     }
     fn minimum_spanning_tree_with_kruskal(&self) -> Self {todo!()}
     fn minimum_spanning_tree_with_prim(&self) -> Self {todo!()}
-    fn shortest_path_with_dijkstra(&self, start: usize) -> HashMap<usize, Option<isize>> {todo!()}
-    fn shortest_path_with_floyd(&self) -> HashMap<usize, HashMap<usize, isize>> {todo!()}
+    fn shortest_path_with_dijkstra(&self, start: usize) -> HashMap<usize, Option<W>> {
+        let tree = self.shortest_path_tree_with_dijkstra(start);
+        self.vertex.keys().map(|&id| (id, tree.distance_to(id))).collect()
+    }
+    fn shortest_path_with_floyd(&self) -> HashMap<usize, HashMap<usize, W>> {
+        self.shortest_path_table_with_floyd().distance
+    }
+    fn empty_like(&self) -> Self {
+        Self::with_vertex_payload(self.graph_type.clone())
+    }
+    fn contains_vertex(&self, id: usize) -> bool {
+        self.vertex.contains_key(&id)
+    }
+    fn is_directed(&self) -> bool {
+        matches!(self.graph_type, GraphType::DG)
+    }
+    fn vertices(&self) -> Vec<usize> {
+        let mut ids: Vec<usize> = self.vertex.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+    fn neighbors(&self, id: usize) -> Vec<(usize, W)> {
+        let mut result = Vec::new();
+        let mut head = self.vertex.get(&id).and_then(|head| head.as_ref());
+        while let Some(node) = head {
+            result.push((node.to, node.weight));
+            head = node.next.as_ref();
+        }
+        result
+    }
 }
 
-impl GraphAdjacencyLinkList {
+impl<W: Weight> GraphAdjacencyLinkList<W> {
     pub fn new(graph_type: GraphType) -> Self {
-        Self { 
-            vertex: HashMap::new(), 
-            graph_type,
-        }
+        Self::with_vertex_payload(graph_type)
     }
-    pub fn from(matrix: Vec<Vec<Option<isize>>>, graph_type: GraphType) -> Self {
+    pub fn from(matrix: Vec<Vec<Option<W>>>, graph_type: GraphType) -> Self {
         let len = matrix.len();
         let mut graph = Self::new(graph_type);
         for i in 0..len {
@@ -236,7 +273,17 @@ impl GraphAdjacencyLinkList {
         }
         graph
     }
-    fn insert_by_sort(head: Option<Box<GraphLinkList>>, to: usize, weight: isize, flag: &mut bool) -> Option<Box<GraphLinkList>> {
+}
+
+impl<W: Weight, V> GraphAdjacencyLinkList<W, V> {
+    pub fn with_vertex_payload(graph_type: GraphType) -> Self {
+        Self { 
+            vertex: HashMap::new(), 
+            payload: HashMap::new(),
+            graph_type,
+        }
+    }
+    fn insert_by_sort(head: Option<Box<GraphLinkList<W>>>, to: usize, weight: W, flag: &mut bool) -> Option<Box<GraphLinkList<W>>> {
         match head {
             Some(mut head) => {
                 if head.to > to {
@@ -250,7 +297,7 @@ impl GraphAdjacencyLinkList {
             None => Some(Box::new(GraphLinkList::from(to, weight, None))),
         }
     }
-    fn delete_by_sort(head: Option<Box<GraphLinkList>>, to: usize, flag: &mut bool) -> Option<Box<GraphLinkList>> {
+    fn delete_by_sort(head: Option<Box<GraphLinkList<W>>>, to: usize, flag: &mut bool) -> Option<Box<GraphLinkList<W>>> {
         match head {
             Some(mut head) => {
                 if head.to == to {
@@ -266,7 +313,7 @@ impl GraphAdjacencyLinkList {
             None => None,
         }
     }
-    fn update_by_sort(head: Option<Box<GraphLinkList>>, to: usize, weight: isize, flag: &mut bool) -> Option<Box<GraphLinkList>> {
+    fn update_by_sort(head: Option<Box<GraphLinkList<W>>>, to: usize, weight: W, flag: &mut bool) -> Option<Box<GraphLinkList<W>>> {
         match head {
             Some(mut head) => {
                 if head.to == to {
@@ -280,7 +327,7 @@ impl GraphAdjacencyLinkList {
             None => None,
         }
     }
-    fn set_edge_map<'a>(head: &'a Option<Box<GraphLinkList>>, map: &mut HashMap<usize, &'a isize>) {
+    fn set_edge_map<'a>(head: &'a Option<Box<GraphLinkList<W>>>, map: &mut HashMap<usize, &'a W>) {
         match head {
             Some(head) => {
                 map.insert(head.to, &head.weight);
@@ -291,8 +338,8 @@ impl GraphAdjacencyLinkList {
     }
 }
 
-impl GraphLinkList {
-    pub fn from(to: usize, weight: isize, next: Option<Box<GraphLinkList>>) -> Self {
+impl<W: Weight> GraphLinkList<W> {
+    pub fn from(to: usize, weight: W, next: Option<Box<GraphLinkList<W>>>) -> Self {
         Self { 
             to, 
             weight, 
@@ -1,19 +1,21 @@
-use std::collections::{HashMap, HashSet, BinaryHeap};
-use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::cmp::Ordering;
 
-use crate::datastruct::graph::{GraphType, GraphTrait};
+use crate::datastruct::graph::{GraphType, GraphTrait, Weight};
 use crate::datastruct::graph::union_find_set::UnionFindSet;
+use crate::datastruct::heap::heap::Heap;
 
 #[derive(Debug, Clone)]
-pub struct GraphAdjacencyMatrix {
+pub struct GraphAdjacencyMatrix<W: Weight = isize, V = ()> {
     pub vertex: HashMap<usize, usize>,
     pub ids: HashMap<usize, usize>,
-    pub matrix: Vec<Vec<Option<isize>>>,
+    pub matrix: Vec<Vec<Option<W>>>,
+    pub payload: HashMap<usize, V>,
     pub graph_type: GraphType,
 }
 
 
-impl GraphTrait for GraphAdjacencyMatrix {
+impl<W: Weight, V: Clone> GraphTrait<W, V> for GraphAdjacencyMatrix<W, V> {
     fn print(&self) {
         let mut ids: Vec<(&usize, &usize)> = self.vertex.iter().collect();
         ids.sort_by(|(id1, _), (id2, _)| id1.cmp(id2));
@@ -26,14 +28,14 @@ impl GraphTrait for GraphAdjacencyMatrix {
             print!("{}\t", id_from);
             for (_, &index_to) in ids.iter() {
                 match self.matrix[index_from][index_to] {
-                    Some(weight) => print!("{}\t", weight),
+                    Some(weight) => print!("{:?}\t", weight),
                     None => print!("nan\t"),
                 } 
             }
             println!("");
         }
     }
-    fn convert_to_vector(&self) -> Vec<Vec<Option<isize>>> {
+    fn convert_to_vector(&self) -> Vec<Vec<Option<W>>> {
         let mut result = vec![vec![None; self.vertex.len()]; self.vertex.len()];
         let mut ids: Vec<(&usize, &usize)> = self.vertex.iter().collect();
         ids.sort_by(|(id1, _), (id2, _)| id1.cmp(id2));
@@ -68,6 +70,7 @@ impl GraphTrait for GraphAdjacencyMatrix {
         match self.vertex.remove(&id) {
             Some(index) => {
                 self.ids.remove(&index);
+                self.payload.remove(&id);
                 let len = self.vertex.len(); 
                 for i in 0..len + 1 {
                     if i == index {continue;}
@@ -89,7 +92,17 @@ impl GraphTrait for GraphAdjacencyMatrix {
             None => false,
         }
     }
-    fn add_edge(&mut self, (from, to, weight): (usize, usize, isize)) -> bool{
+    fn set_payload(&mut self, id: usize, payload: V) -> bool {
+        if !self.vertex.contains_key(&id) {
+            return false;
+        }
+        self.payload.insert(id, payload);
+        true
+    }
+    fn payload(&self, id: usize) -> Option<&V> {
+        self.payload.get(&id)
+    }
+    fn add_edge(&mut self, (from, to, weight): (usize, usize, W)) -> bool{
         self.add_vertex(from);
         self.add_vertex(to);
         let from_index = self.vertex[&from];
@@ -129,7 +142,7 @@ impl GraphTrait for GraphAdjacencyMatrix {
             None => false,
         }
     }
-    fn update_edge(&mut self, (from, to, weight): (usize, usize, isize)) -> bool{
+    fn update_edge(&mut self, (from, to, weight): (usize, usize, W)) -> bool{
         match self.vertex.get(&from) {
             Some(&from_index) => match self.vertex.get(&to) {
                 Some(&to_index) => match self.matrix[from_index][to_index].as_mut() {
@@ -234,10 +247,9 @@ impl GraphTrait for GraphAdjacencyMatrix {
     }
     fn minimum_spanning_tree_with_kruskal(&self) -> Self {
         let graph_type = self.graph_type.clone();
-        let mut graph = GraphAdjacencyMatrix::new(graph_type.clone());
+        let mut graph = Self::with_vertex_payload(graph_type.clone());
         let mut edges = Vec::new();
         let count = self.vertex.len();
-        let mut vertex_set = HashSet::new();
         match graph_type {
             GraphType::DG => {
                 for from in 0..count {
@@ -258,37 +270,42 @@ impl GraphTrait for GraphAdjacencyMatrix {
                 }
             }
         }
-        edges.sort_by(|(_, _, weight1), (_, _, weight2)| weight2.cmp(weight1));
+        edges.sort_by(|(_, _, weight1), (_, _, weight2)| weight2.partial_cmp(weight1).unwrap_or(Ordering::Equal));
+        // isolated vertices have no edge to bring them in, so add every vertex first
+        for id in self.vertex.keys() {
+            graph.add_vertex(*id);
+        }
         let mut unf = UnionFindSet::new(count); 
-        while vertex_set.len() != count {
-            let (from, to, wight) = edges.pop().unwrap();
+        // covering every vertex is not enough, two disjoint edges may cover four vertices
+        // a disconnected graph runs out of edges and gives a spanning forest
+        while let Some((from, to, wight)) = edges.pop() {
+            if unf.component_count() == 1 {
+                break;
+            }
             let from_index = self.vertex[&from];
             let to_index = self.vertex[&to];
-            if unf.is_union(from_index, to_index) {
+            if !unf.union(from_index, to_index) {
                 continue;
-            } else {
-                unf.union(from_index, to_index);
             }
             graph.add_edge((from, to, wight)); 
-            vertex_set.insert(from);
-            vertex_set.insert(to);
         }
+        self.copy_payload(&mut graph);
         graph
     }
     fn minimum_spanning_tree_with_prim(&self) -> Self {
-        let mut graph = GraphAdjacencyMatrix::new(self.graph_type.clone());
+        let mut graph = Self::with_vertex_payload(self.graph_type.clone());
         let count = self.vertex.len();
         let mut vistied = vec![false; count];
-        let mut heap = BinaryHeap::new();
+        let mut heap = Heap::new();
         let id = self.ids[&0];
         graph.add_vertex(id);
         vistied[0] = true;
         for i in 0..count {
             if let Some(weight) = self.matrix[0][i] {
-                heap.push(Reverse((weight, 0, i)));
+                heap.push((weight, 0, i));
             } 
         }
-        while let Some(Reverse((weight, from_index, to_index))) = heap.pop() {
+        while let Some((weight, from_index, to_index)) = heap.pop() {
             if vistied[to_index] {
                 continue;
             } else {
@@ -300,13 +317,14 @@ impl GraphTrait for GraphAdjacencyMatrix {
             for i in 0..count {
                 if vistied[i] {continue;}
                 if let Some(weight) = self.matrix[to_index][i] {
-                    heap.push(Reverse((weight, to_index, i)));
+                    heap.push((weight, to_index, i));
                 }
             }
         }
+        self.copy_payload(&mut graph);
         graph
     }
-    fn shortest_path_with_dijkstra(&self, start: usize) -> HashMap<usize, Option<isize>> {
+    fn shortest_path_with_dijkstra(&self, start: usize) -> HashMap<usize, Option<W>> {
         let count = self.vertex.len();
         let mut vistied = vec![false; count];
         let mut distance = HashMap::new();
@@ -314,11 +332,11 @@ impl GraphTrait for GraphAdjacencyMatrix {
             let id = self.ids[&i];
             distance.insert(id, None);
         }
-        let mut heap = BinaryHeap::new();
+        let mut heap = Heap::new();
         let index = self.vertex[&start];
-        heap.push(Reverse((0, index)));
-        distance.insert(start, Some(0));
-        while let Some(Reverse((wight, index))) = heap.pop() {
+        heap.push((W::zero(), index));
+        distance.insert(start, Some(W::zero()));
+        while let Some((wight, index)) = heap.pop() {
             if vistied[index] {
                 continue;
             } 
@@ -329,11 +347,11 @@ impl GraphTrait for GraphAdjacencyMatrix {
                     match distance[&id] {
                         Some(old_weight) => if old_weight > wight + w {
                             distance.insert(id, Some(wight + w));
-                            heap.push(Reverse((wight + w, i)));
+                            heap.push((wight + w, i));
                         }
                         None => {
                             distance.insert(id, Some(wight + w));
-                            heap.push(Reverse((wight + w, i)));
+                            heap.push((wight + w, i));
                         }
                     } 
                 }
@@ -341,8 +359,8 @@ impl GraphTrait for GraphAdjacencyMatrix {
         }
         distance
     }
-    fn shortest_path_with_floyd(&self) -> HashMap<usize, HashMap<usize, isize>> {
-        fn get_weight_with_transfer(path: &HashMap<usize, HashMap<usize, isize>>, from: usize, to: usize, transfer: usize) -> (Option<isize>, Option<isize>) {
+    fn shortest_path_with_floyd(&self) -> HashMap<usize, HashMap<usize, W>> {
+        fn get_weight_with_transfer<W: Weight>(path: &HashMap<usize, HashMap<usize, W>>, from: usize, to: usize, transfer: usize) -> (Option<W>, Option<W>) {
             match (path.get(&from), path.get(&transfer)) {
                 (Some(from_map), Some(trans_map)) => match (from_map.get(&to), from_map.get(&transfer), trans_map.get(&to)) {
                     (Some(w_f_t), Some(w_f_tans), Some(w_trans_t)) => (Some(*w_f_t), Some(*w_f_tans + *w_trans_t)),
@@ -357,7 +375,7 @@ impl GraphTrait for GraphAdjacencyMatrix {
         let mut path = HashMap::new();
         for (id, _) in self.vertex.iter() {
             let mut map = HashMap::new();
-            map.insert(*id, 0);
+            map.insert(*id, W::zero());
             path.insert(*id, map);
         }
         for from_index in 0..count {
@@ -399,19 +417,37 @@ impl GraphTrait for GraphAdjacencyMatrix {
         }
         path
     }
+    fn empty_like(&self) -> Self {
+        Self::with_vertex_payload(self.graph_type.clone())
+    }
+    fn contains_vertex(&self, id: usize) -> bool {
+        self.vertex.contains_key(&id)
+    }
+    fn is_directed(&self) -> bool {
+        matches!(self.graph_type, GraphType::DG)
+    }
+    fn vertices(&self) -> Vec<usize> {
+        let mut ids: Vec<usize> = self.vertex.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+    fn neighbors(&self, id: usize) -> Vec<(usize, W)> {
+        match self.vertex.get(&id) {
+            Some(&index) => self.matrix[index].iter()
+                .enumerate()
+                .filter_map(|(i, weight)| weight.map(|w| (self.ids[&i], w)))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
 }
 
 
-impl GraphAdjacencyMatrix {
+impl<W: Weight> GraphAdjacencyMatrix<W> {
     pub fn new(graph_type: GraphType) -> Self {
-        Self { 
-            vertex: HashMap::new(),
-            ids: HashMap::new(),
-            matrix: Vec::new(), 
-            graph_type,
-        }
+        Self::with_vertex_payload(graph_type)
     }
-    pub fn from(matrix: Vec<Vec<Option<isize>>>, graph_type: GraphType) -> Self {
+    pub fn from(matrix: Vec<Vec<Option<W>>>, graph_type: GraphType) -> Self {
         let len = matrix.len();
         let mut graph = GraphAdjacencyMatrix::new(graph_type);
         for i in 0..len {
@@ -425,3 +461,26 @@ impl GraphAdjacencyMatrix {
         graph
     }
 }
+
+impl<W: Weight, V> GraphAdjacencyMatrix<W, V> {
+    pub fn with_vertex_payload(graph_type: GraphType) -> Self {
+        Self { 
+            vertex: HashMap::new(),
+            ids: HashMap::new(),
+            matrix: Vec::new(), 
+            payload: HashMap::new(),
+            graph_type,
+        }
+    }
+}
+
+impl<W: Weight, V: Clone> GraphAdjacencyMatrix<W, V> {
+    // a graph derived from this one keeps the payload of every vertex it contains
+    fn copy_payload(&self, graph: &mut Self) {
+        for (id, payload) in self.payload.iter() {
+            if graph.vertex.contains_key(id) {
+                graph.payload.insert(*id, payload.clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::datastruct::graph::union_find_set::UnionFindSet;
+
+// elements get dense indices in the order they are first seen
+pub struct HashUnionFind<T: Hash + Eq + Clone> {
+    pub index: HashMap<T, usize>,
+    pub elements: Vec<T>,
+    pub unf: UnionFindSet,
+}
+
+impl<T: Hash + Eq + Clone> HashUnionFind<T> {
+    pub fn new() -> Self {
+        Self {
+            index: HashMap::new(),
+            elements: Vec::new(),
+            unf: UnionFindSet::new(0),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+    pub fn contains(&self, a: &T) -> bool {
+        self.index.contains_key(a)
+    }
+    // returns the index of a, adding it as a component of its own when unseen
+    pub fn add_element(&mut self, a: T) -> usize {
+        if let Some(index) = self.index.get(&a) {
+            return *index;
+        }
+        let index = self.unf.add_element();
+        self.index.insert(a.clone(), index);
+        self.elements.push(a);
+        index
+    }
+    // unseen elements are added first
+    pub fn union(&mut self, a: T, b: T) -> bool {
+        let a = self.add_element(a);
+        let b = self.add_element(b);
+        self.unf.union(a, b)
+    }
+    // the representative of the component holding a
+    pub fn find(&mut self, a: &T) -> Option<&T> {
+        let index = *self.index.get(a)?;
+        let root = self.unf.find(index);
+        Some(&self.elements[root])
+    }
+    pub fn is_union(&mut self, a: &T, b: &T) -> bool {
+        match (self.index.get(a), self.index.get(b)) {
+            (Some(&a), Some(&b)) => self.unf.is_union(a, b),
+            _ => a == b,
+        }
+    }
+    pub fn component_count(&self) -> usize {
+        self.unf.component_count()
+    }
+    pub fn size_of(&mut self, a: &T) -> usize {
+        match self.index.get(a) {
+            Some(&index) => self.unf.size_of(index),
+            None => 1,
+        }
+    }
+    // components ordered by the first member that was added, members in insertion order
+    pub fn groups(&mut self) -> Vec<Vec<T>> {
+        self.unf.groups()
+            .into_iter()
+            .map(|group| group.into_iter().map(|index| self.elements[index].clone()).collect())
+            .collect()
+    }
+}
+
+impl<T: Hash + Eq + Clone> Default for HashUnionFind<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,24 +1,127 @@
 use std::collections::HashMap;
+use std::fmt::Debug;
+use std::ops::{Add, Sub};
+
+use crate::datastruct::graph::search::ReverseIndex;
+use crate::datastruct::graph::shortest_path::{AllPairsShortestPath, ShortestPathError, ShortestPathTree};
 
 pub mod union_find_set;
+pub mod hash_union_find;
+pub mod rollback_union_find;
+pub mod weighted_union_find;
 pub mod adjacency_matrix;
 pub mod adjacency_linklist;
+pub mod shortest_path;
+pub mod spanning_tree;
+pub mod search;
+
+// edge weights only need to be added and compared, zero is the length of an empty path
+pub trait Weight: Copy + PartialOrd + Add<Output = Self> + Debug {
+    fn zero() -> Self;
+}
+
+macro_rules! impl_weight {
+    ($($t:ty),*) => {
+        $(impl Weight for $t {
+            fn zero() -> Self {
+                0 as $t
+            }
+        })*
+    };
+}
 
-pub trait GraphTrait {
+impl_weight!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+// vertices are identified by usize ids and may carry a payload of type V
+pub trait GraphTrait<W: Weight = isize, V = ()> {
     fn print(&self);
-    fn convert_to_vector(&self) -> Vec<Vec<Option<isize>>>;
+    fn convert_to_vector(&self) -> Vec<Vec<Option<W>>>;
     fn add_vertex(&mut self, id: usize) -> bool;
     fn delete_vertex(&mut self, id: usize) -> bool;
-    fn add_edge(&mut self, info: (usize, usize, isize)) -> bool; 
+    fn set_payload(&mut self, id: usize, payload: V) -> bool;
+    fn payload(&self, id: usize) -> Option<&V>;
+    fn add_edge(&mut self, info: (usize, usize, W)) -> bool; 
     fn delete_edge(&mut self, info: (usize, usize)) -> bool;
-    fn update_edge(&mut self, info: (usize, usize, isize)) -> bool;
+    fn update_edge(&mut self, info: (usize, usize, W)) -> bool;
     fn dfs(&self, start: usize) -> Vec<usize>;
     fn bfs(&self, start: usize) -> Vec<usize>;
     fn topo_sort(&self) -> Option<Vec<usize>>;
     fn minimum_spanning_tree_with_kruskal(&self) -> Self;
     fn minimum_spanning_tree_with_prim(&self) -> Self;
-    fn shortest_path_with_dijkstra(&self, start: usize) -> HashMap<usize, Option<isize>>;
-    fn shortest_path_with_floyd(&self) -> HashMap<usize, HashMap<usize, isize>>;
+    fn shortest_path_with_dijkstra(&self, start: usize) -> HashMap<usize, Option<W>>;
+    fn shortest_path_with_floyd(&self) -> HashMap<usize, HashMap<usize, W>>;
+    // ids in increasing order
+    fn vertices(&self) -> Vec<usize>;
+    fn neighbors(&self, id: usize) -> Vec<(usize, W)>;
+    fn contains_vertex(&self, id: usize) -> bool;
+    fn is_directed(&self) -> bool;
+    // an empty graph of the same kind
+    fn empty_like(&self) -> Self where Self: Sized;
+    fn shortest_path_tree_with_dijkstra(&self, start: usize) -> ShortestPathTree<W> {
+        shortest_path::dijkstra(self, start)
+    }
+    // the same results as the methods above, built on IndexedHeap with decrease_key
+    fn shortest_path_tree_with_dijkstra_indexed(&self, start: usize) -> ShortestPathTree<W> {
+        shortest_path::dijkstra_indexed(self, start)
+    }
+    fn shortest_path_with_dijkstra_indexed(&self, start: usize) -> HashMap<usize, Option<W>> {
+        let tree = self.shortest_path_tree_with_dijkstra_indexed(start);
+        self.vertices().into_iter().map(|id| (id, tree.distance_to(id))).collect()
+    }
+    // keeps every vertex and its payload, a disconnected graph gives a spanning forest
+    fn minimum_spanning_tree_with_prim_indexed(&self) -> Self
+    where
+        Self: Sized,
+        V: Clone,
+    {
+        let mut graph = self.empty_like();
+        for id in self.vertices() {
+            graph.add_vertex(id);
+            if let Some(payload) = self.payload(id) {
+                graph.set_payload(id, payload.clone());
+            }
+        }
+        for edge in spanning_tree::prim_indexed(self) {
+            graph.add_edge(edge);
+        }
+        graph
+    }
+    fn shortest_path_table_with_floyd(&self) -> AllPairsShortestPath<W> {
+        shortest_path::floyd(self)
+    }
+    // unlike dijkstra these are correct with negative weights
+    fn shortest_path_tree_with_bellman_ford(&self, start: usize) -> Result<ShortestPathTree<W>, ShortestPathError> {
+        shortest_path::bellman_ford(self, start)
+    }
+    fn shortest_path_tree_with_spfa(&self, start: usize) -> Result<ShortestPathTree<W>, ShortestPathError> {
+        shortest_path::spfa(self, start)
+    }
+    fn shortest_path_table_with_johnson(&self) -> Result<AllPairsShortestPath<W>, ShortestPathError>
+    where
+        W: Sub<Output = W>,
+    {
+        shortest_path::johnson(self)
+    }
+    // cost and vertices of a shortest path from start to target
+    fn path_to(&self, start: usize, target: usize) -> Option<(W, Vec<usize>)> {
+        let tree = self.shortest_path_tree_with_dijkstra(start);
+        Some((tree.distance_to(target)?, tree.path_to(target)?))
+    }
+    // heuristic estimates the cost from a vertex to target
+    fn path_with_a_star<F: Fn(usize) -> W>(&self, start: usize, target: usize, heuristic: F) -> Option<(W, Vec<usize>)> {
+        search::a_star(self, start, target, heuristic)
+    }
+    // shared by the bidirectional searches, rebuild it after changing the graph
+    fn reverse_index(&self) -> ReverseIndex<W> {
+        ReverseIndex::new(self)
+    }
+    fn path_with_bidirectional_dijkstra(&self, reverse: &ReverseIndex<W>, start: usize, target: usize) -> Option<(W, Vec<usize>)> {
+        search::bidirectional_dijkstra(self, reverse, start, target)
+    }
+    // the cost is the number of edges
+    fn path_with_bidirectional_bfs(&self, reverse: &ReverseIndex<W>, start: usize, target: usize) -> Option<(usize, Vec<usize>)> {
+        search::bidirectional_bfs(self, reverse, start, target)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -106,6 +209,7 @@ const MATRIX4: [[isize; 7]; 7] = [
 mod test_graph_adjacency_matrix {
     use crate::datastruct::graph::*;
     use self::adjacency_matrix::GraphAdjacencyMatrix;
+    use self::adjacency_linklist::GraphAdjacencyLinkList;
     #[test]
     fn test_dfs() {
         let mut matrix1 = Vec::new();
@@ -141,6 +245,53 @@ mod test_graph_adjacency_matrix {
         assert_eq!(mst.convert_to_vector(), gen_matrix_with_zero_present_nan(mmst));
     }
     #[test]
+    fn test_mst_kruskal_disjoint_edges() {
+        // the two cheapest edges already cover every vertex but leave two components
+        let matrix = vec![
+            vec![0, 1, 0, 6],
+            vec![1, 0, 5, 0],
+            vec![0, 5, 0, 1],
+            vec![6, 0, 1, 0],
+        ];
+        let graph = GraphAdjacencyMatrix::from(gen_matrix_with_zero_present_nan(matrix), GraphType::NDG);
+        let mst = graph.minimum_spanning_tree_with_kruskal();
+        let weights: Vec<isize> = mst.convert_to_vector().into_iter().flatten().flatten().collect();
+        assert_eq!(weights.len(), 6);
+        assert_eq!(weights.iter().sum::<isize>(), 14);
+    }
+    #[test]
+    fn test_mst_kruskal_disconnected() {
+        // no edge joins {0, 1} with {2, 3}, so the result is a spanning forest
+        let matrix = vec![
+            vec![0, 2, 0, 0],
+            vec![2, 0, 0, 0],
+            vec![0, 0, 0, 3],
+            vec![0, 0, 3, 0],
+        ];
+        let graph = GraphAdjacencyMatrix::from(gen_matrix_with_zero_present_nan(matrix.clone()), GraphType::NDG);
+        let mst = graph.minimum_spanning_tree_with_kruskal();
+        assert_eq!(mst.convert_to_vector(), gen_matrix_with_zero_present_nan(matrix));
+    }
+    #[test]
+    fn test_mst_kruskal_isolated_vertex() {
+        // a triangle, a single edge and a vertex with no edges at all
+        let mut graph: GraphAdjacencyMatrix<isize, &str> = GraphAdjacencyMatrix::with_vertex_payload(GraphType::NDG);
+        for edge in [(0, 1, 1), (1, 2, 2), (0, 2, 3), (5, 6, 4)] {
+            graph.add_edge(edge);
+        }
+        graph.add_vertex(9);
+        graph.set_payload(9, "alone");
+        graph.set_payload(5, "five");
+        let mst = graph.minimum_spanning_tree_with_kruskal();
+        assert_eq!(mst.vertices(), vec![0, 1, 2, 5, 6, 9]);
+        let total: isize = mst.convert_to_vector().into_iter().flatten().flatten().sum();
+        // every undirected edge shows up twice in the matrix
+        assert_eq!(total, 2 * (1 + 2 + 4));
+        assert_eq!(mst.payload(9), Some(&"alone"));
+        assert_eq!(mst.payload(5), Some(&"five"));
+        assert_eq!(mst.neighbors(9), vec![]);
+    }
+    #[test]
     fn test_mst_prime() {
         let mut matrix = Vec::new();
         for r in MATRIX2 {
@@ -166,6 +317,47 @@ mod test_graph_adjacency_matrix {
         assert_eq!(path_test, path);
     }
     #[test]
+    fn test_mst_prime_indexed() {
+        let mut matrix = Vec::new();
+        for r in MATRIX2 {
+            matrix.push(r.to_vec());
+        }
+        let graph = GraphAdjacencyMatrix::from(gen_matrix_with_zero_present_nan(matrix.clone()), GraphType::NDG); 
+        let linklist = GraphAdjacencyLinkList::from(gen_matrix_with_zero_present_nan(matrix), GraphType::NDG); 
+        let mst = graph.minimum_spanning_tree_with_prim_indexed();
+        let mut mmst = Vec::new();
+        for r in MINSPANTREEFORMATRIX2 {
+            mmst.push(r.to_vec());
+        }
+        assert_eq!(mst.convert_to_vector(), gen_matrix_with_zero_present_nan(mmst.clone()));
+        assert_eq!(mst.convert_to_vector(), graph.minimum_spanning_tree_with_prim().convert_to_vector());
+        assert_eq!(linklist.minimum_spanning_tree_with_prim_indexed().convert_to_vector(), gen_matrix_with_zero_present_nan(mmst));
+    }
+    #[test]
+    fn test_sp_dijkstra_indexed() {
+        let mut matrix = Vec::new();
+        for r in MATRIX3 {
+            matrix.push(r.to_vec());
+        }
+        let graph = GraphAdjacencyMatrix::from(gen_matrix_with_zero_present_nan(matrix.clone()), GraphType::NDG); 
+        let linklist = GraphAdjacencyLinkList::from(gen_matrix_with_zero_present_nan(matrix), GraphType::NDG); 
+        assert_eq!(graph.shortest_path_with_dijkstra_indexed(0), graph.shortest_path_with_dijkstra(0));
+        assert_eq!(linklist.shortest_path_with_dijkstra_indexed(0), graph.shortest_path_with_dijkstra(0));
+        let mut matrix = Vec::new();
+        for r in MATRIX1 {
+            matrix.push(r.to_vec());
+        }
+        let graph = GraphAdjacencyMatrix::from(gen_matrix_with_zero_present_nan(matrix.clone()), GraphType::DG); 
+        let linklist = GraphAdjacencyLinkList::from(gen_matrix_with_zero_present_nan(matrix), GraphType::DG); 
+        let path: HashMap<usize, Option<isize>> = vec![(0, Some(0)), (1, Some(5)), (2, Some(21)), (3, Some(12)), (4, Some(6)), (5, Some(14))].into_iter().collect();
+        assert_eq!(graph.shortest_path_with_dijkstra_indexed(0), path);
+        assert_eq!(linklist.shortest_path_with_dijkstra_indexed(0), path);
+        let path: HashMap<usize, Option<isize>> = vec![(0, None), (1, None), (2, Some(9)), (3, Some(0)), (4, None), (5, None)].into_iter().collect();
+        assert_eq!(graph.shortest_path_with_dijkstra_indexed(3), path);
+        assert_eq!(linklist.shortest_path_with_dijkstra_indexed(3), path);
+        assert_eq!(linklist.shortest_path_tree_with_dijkstra_indexed(0), linklist.shortest_path_tree_with_dijkstra(0));
+    }
+    #[test]
     fn test_sp_floyd() {
         let mut matrix = Vec::new();
         for r in MATRIX4 {
@@ -210,3 +402,610 @@ mod test_graph_adjacency_linklist {
         assert!(path == vec![0, 1, 4, 3, 5, 2] || path == vec![0, 4, 1, 5, 3, 2]);
     }
 }
+
+#[cfg(test)]
+mod test_union_find_set {
+    use crate::datastruct::graph::union_find_set::UnionFindSet;
+    use rand::{Rng, thread_rng};
+    #[test]
+    fn test_union() {
+        let mut unf = UnionFindSet::new(5);
+        assert_eq!(unf.component_count(), 5);
+        assert!(unf.union(0, 1));
+        assert!(unf.union(3, 4));
+        assert!(!unf.union(1, 0));
+        assert!(unf.union(1, 4));
+        assert!(unf.is_union(0, 3));
+        assert!(!unf.is_union(0, 2));
+        assert_eq!(unf.component_count(), 2);
+        assert_eq!(unf.size_of(4), 4);
+        assert_eq!(unf.size_of(2), 1);
+        assert_eq!(unf.groups(), vec![vec![0, 1, 3, 4], vec![2]]);
+        assert_eq!(unf.add_element(), 5);
+        assert_eq!(unf.component_count(), 3);
+        assert!(unf.union(5, 2));
+        assert_eq!(unf.groups(), vec![vec![0, 1, 3, 4], vec![2, 5]]);
+        assert_eq!(unf.len(), 6);
+    }
+    #[test]
+    fn test_random() {
+        let size = 300;
+        let mut unf = UnionFindSet::new(size);
+        let mut label: Vec<usize> = (0..size).collect();
+        for _ in 0..400 {
+            let a = thread_rng().gen_range(0..size);
+            let b = thread_rng().gen_range(0..size);
+            let (la, lb) = (label[a], label[b]);
+            assert_eq!(unf.union(a, b), la != lb);
+            for l in label.iter_mut() {
+                if *l == la {
+                    *l = lb;
+                }
+            }
+            let mut labels = label.clone();
+            labels.sort();
+            labels.dedup();
+            assert_eq!(unf.component_count(), labels.len());
+            let c = thread_rng().gen_range(0..size);
+            assert_eq!(unf.size_of(c), label.iter().filter(|l| **l == label[c]).count());
+        }
+        for group in unf.groups() {
+            assert!(group.iter().all(|member| label[*member] == label[group[0]]));
+            assert_eq!(group.len(), label.iter().filter(|l| **l == label[group[0]]).count());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_hash_union_find {
+    use std::collections::HashMap;
+    use crate::datastruct::graph::hash_union_find::HashUnionFind;
+    use rand::{Rng, thread_rng};
+    #[test]
+    fn test_union() {
+        let mut unf = HashUnionFind::new();
+        assert!(unf.union("a", "b"));
+        assert!(unf.union("c", "d"));
+        assert!(!unf.union("b", "a"));
+        assert!(unf.is_union(&"a", &"b"));
+        assert!(!unf.is_union(&"a", &"c"));
+        assert!(!unf.is_union(&"a", &"x"));
+        assert!(unf.is_union(&"x", &"x"));
+        assert_eq!(unf.find(&"x"), None);
+        let root = unf.find(&"b").cloned();
+        assert_eq!(unf.find(&"a").cloned(), root);
+        assert_eq!(unf.add_element("e"), 4);
+        assert_eq!(unf.component_count(), 3);
+        assert!(unf.union("d", "a"));
+        assert_eq!(unf.size_of(&"c"), 4);
+        assert_eq!(unf.size_of(&"x"), 1);
+        assert_eq!(unf.groups(), vec![vec!["a", "b", "c", "d"], vec!["e"]]);
+        assert_eq!(unf.len(), 5);
+    }
+    #[test]
+    fn test_random() {
+        let mut unf = HashUnionFind::new();
+        let mut label: HashMap<String, usize> = HashMap::new();
+        for i in 0..500 {
+            let a = format!("v{}", thread_rng().gen_range(0..200));
+            let b = format!("v{}", thread_rng().gen_range(0..200));
+            let la = *label.entry(a.clone()).or_insert(i * 2);
+            let lb = *label.entry(b.clone()).or_insert(i * 2 + 1);
+            assert_eq!(unf.union(a.clone(), b.clone()), la != lb);
+            for l in label.values_mut() {
+                if *l == la {
+                    *l = lb;
+                }
+            }
+            let c = format!("v{}", thread_rng().gen_range(0..200));
+            let d = format!("v{}", thread_rng().gen_range(0..200));
+            let expect = c == d || (label.contains_key(&c) && label.get(&c) == label.get(&d));
+            assert_eq!(unf.is_union(&c, &d), expect);
+        }
+        let mut labels: Vec<usize> = label.values().cloned().collect();
+        labels.sort();
+        labels.dedup();
+        assert_eq!(unf.component_count(), labels.len());
+        assert_eq!(unf.len(), label.len());
+    }
+}
+
+#[cfg(test)]
+mod test_rollback_union_find {
+    use crate::datastruct::graph::rollback_union_find::RollbackUnionFind;
+    use crate::datastruct::graph::union_find_set::UnionFindSet;
+    use rand::{Rng, thread_rng};
+    #[test]
+    fn test_rollback() {
+        let mut unf = RollbackUnionFind::new(4);
+        assert!(unf.union(0, 1));
+        let snapshot = unf.snapshot();
+        assert!(unf.union(2, 3));
+        assert!(!unf.union(3, 2));
+        assert!(unf.union(1, 2));
+        assert!(unf.is_union(0, 3));
+        assert_eq!(unf.component_count(), 1);
+        unf.rollback(snapshot);
+        assert!(unf.is_union(0, 1));
+        assert!(!unf.is_union(1, 2));
+        assert!(!unf.is_union(2, 3));
+        assert_eq!(unf.component_count(), 3);
+        assert!(unf.undo());
+        assert!(!unf.undo());
+        assert_eq!(unf.component_count(), 4);
+        assert_eq!(unf.rank, vec![0; 4]);
+    }
+    #[test]
+    fn test_random() {
+        let size = 50;
+        let mut unf = RollbackUnionFind::new(size);
+        let mut unions: Vec<(usize, usize)> = Vec::new();
+        let mut snapshots: Vec<(usize, usize)> = Vec::new();
+        for _ in 0..1000 {
+            match thread_rng().gen_range(0..10) {
+                0 => snapshots.push((unf.snapshot(), unions.len())),
+                1 => if let Some((snapshot, len)) = snapshots.pop() {
+                    unf.rollback(snapshot);
+                    unions.truncate(len);
+                }
+                _ => {
+                    let a = thread_rng().gen_range(0..size);
+                    let b = thread_rng().gen_range(0..size);
+                    unf.union(a, b);
+                    unions.push((a, b));
+                }
+            }
+            let mut standard = UnionFindSet::new(size);
+            for (a, b) in unions.iter() {
+                standard.union(*a, *b);
+            }
+            assert_eq!(unf.component_count(), standard.component_count());
+            let a = thread_rng().gen_range(0..size);
+            let b = thread_rng().gen_range(0..size);
+            assert_eq!(unf.is_union(a, b), standard.is_union(a, b));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_weighted_union_find {
+    use crate::datastruct::graph::weighted_union_find::{WeightedUnionError, WeightedUnionFind};
+    use rand::{Rng, thread_rng};
+    #[test]
+    fn test_potential() {
+        let mut unf = WeightedUnionFind::new(4);
+        assert_eq!(unf.union(0, 1, 3), Ok(true));
+        assert_eq!(unf.union(1, 2, -5), Ok(true));
+        assert_eq!(unf.diff(0, 2), Some(-2));
+        assert_eq!(unf.diff(2, 0), Some(2));
+        assert_eq!(unf.diff(0, 3), None);
+        assert_eq!(unf.union(0, 2, -2), Ok(false));
+        assert_eq!(unf.union(2, 0, 7), Err(WeightedUnionError::Contradiction(2)));
+        assert_eq!(unf.union(3, 2, 1), Ok(true));
+        assert_eq!(unf.diff(3, 0), Some(3));
+        assert!(unf.is_union(3, 1));
+        let mut unf = WeightedUnionFind::new(2);
+        assert_eq!(unf.union(0, 1, 0.5), Ok(true));
+        assert_eq!(unf.diff(1, 0), Some(-0.5));
+    }
+    #[test]
+    fn test_random() {
+        let size = 100;
+        let potential: Vec<i64> = (0..size).map(|_| thread_rng().gen_range(-1000..1000)).collect();
+        let mut unf = WeightedUnionFind::new(size);
+        let mut label: Vec<usize> = (0..size).collect();
+        for _ in 0..500 {
+            let a = thread_rng().gen_range(0..size);
+            let b = thread_rng().gen_range(0..size);
+            let d = potential[a] - potential[b];
+            let (la, lb) = (label[a], label[b]);
+            if thread_rng().gen_ratio(1, 5) && la == lb && a != b {
+                assert_eq!(unf.union(a, b, d + 1), Err(WeightedUnionError::Contradiction(d)));
+            } else {
+                assert_eq!(unf.union(a, b, d), Ok(la != lb));
+            }
+            for l in label.iter_mut() {
+                if *l == la {
+                    *l = lb;
+                }
+            }
+            let c = thread_rng().gen_range(0..size);
+            let e = thread_rng().gen_range(0..size);
+            let expect = if label[c] == label[e] {Some(potential[c] - potential[e])} else {None};
+            assert_eq!(unf.diff(c, e), expect);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_graph_weight {
+    use std::collections::HashMap;
+    use std::ops::Add;
+    use crate::datastruct::graph::*;
+    use self::adjacency_matrix::GraphAdjacencyMatrix;
+    use self::adjacency_linklist::GraphAdjacencyLinkList;
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Cents(u64);
+    impl Add for Cents {
+        type Output = Cents;
+        fn add(self, other: Cents) -> Cents {
+            Cents(self.0 + other.0)
+        }
+    }
+    impl Weight for Cents {
+        fn zero() -> Self {
+            Cents(0)
+        }
+    }
+    #[test]
+    fn test_float_weight() {
+        let mut graph = GraphAdjacencyMatrix::new(GraphType::DG);
+        graph.add_edge((0, 1, 0.5));
+        graph.add_edge((1, 2, 0.25));
+        graph.add_edge((0, 2, 1.0));
+        let distance: HashMap<usize, Option<f64>> = vec![(0, Some(0.0)), (1, Some(0.5)), (2, Some(0.75))].into_iter().collect();
+        assert_eq!(graph.shortest_path_with_dijkstra(0), distance);
+        assert_eq!(graph.shortest_path_with_dijkstra_indexed(0), distance);
+        assert_eq!(graph.shortest_path_with_floyd()[&0][&2], 0.75);
+        let mst = graph.minimum_spanning_tree_with_kruskal();
+        assert_eq!(mst.convert_to_vector(), vec![vec![None, Some(0.5), None], vec![None, None, Some(0.25)], vec![None, None, None]]);
+    }
+    #[test]
+    fn test_custom_weight() {
+        let mut matrix = Vec::new();
+        for r in MATRIX3 {
+            matrix.push(r.iter().map(|w| if *w == 0 {None} else {Some(Cents(*w as u64))}).collect());
+        }
+        let graph = GraphAdjacencyMatrix::from(matrix.clone(), GraphType::NDG);
+        let distance: HashMap<usize, Option<Cents>> = vec![(0, 0), (1, 6), (2, 11), (3, 4), (4, 10), (5, 16)]
+            .into_iter().map(|(id, w)| (id, Some(Cents(w)))).collect();
+        assert_eq!(graph.shortest_path_with_dijkstra(0), distance);
+        let mut unsigned = Vec::new();
+        for r in MATRIX2 {
+            unsigned.push(r.iter().map(|w| if *w == 0 {None} else {Some(*w as u64)}).collect());
+        }
+        let graph = GraphAdjacencyMatrix::from(unsigned, GraphType::NDG);
+        let total: u64 = graph.minimum_spanning_tree_with_prim().convert_to_vector().into_iter().flatten().flatten().sum();
+        assert_eq!(total, 78);
+        let graph = GraphAdjacencyLinkList::from(matrix, GraphType::NDG);
+        assert_eq!(graph.convert_to_vector()[0][1], Some(Cents(10)));
+    }
+    #[test]
+    fn test_payload() {
+        let mut graph: GraphAdjacencyMatrix<f64, &str> = GraphAdjacencyMatrix::with_vertex_payload(GraphType::NDG);
+        assert!(!graph.set_payload(0, "depot"));
+        graph.add_edge((0, 1, 2.5));
+        graph.add_edge((1, 2, 1.5));
+        graph.add_edge((0, 2, 3.5));
+        assert!(graph.set_payload(0, "depot"));
+        assert!(graph.set_payload(2, "shop"));
+        assert_eq!(graph.payload(0), Some(&"depot"));
+        assert_eq!(graph.payload(1), None);
+        let mst = graph.minimum_spanning_tree_with_prim();
+        assert_eq!(mst.payload(2), Some(&"shop"));
+        assert!(graph.delete_vertex(2));
+        assert_eq!(graph.payload(2), None);
+        let mut graph: GraphAdjacencyLinkList<u64, String> = GraphAdjacencyLinkList::with_vertex_payload(GraphType::DG);
+        graph.add_edge((3, 4, 7));
+        assert!(graph.set_payload(4, "warehouse".to_string()));
+        assert_eq!(graph.payload(4).map(|s| s.as_str()), Some("warehouse"));
+        assert!(graph.delete_vertex(4));
+        assert_eq!(graph.payload(4), None);
+    }
+}
+
+#[cfg(test)]
+mod test_util {
+    use std::ops::Range;
+    use crate::datastruct::graph::*;
+    use rand::{Rng, thread_rng};
+    // a path must start and end where asked and its edges must add up to the cost
+    pub fn check_path(graph: &impl GraphTrait, start: usize, target: usize, cost: isize, path: &[usize]) {
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&target));
+        let mut total = 0;
+        for pair in path.windows(2) {
+            let edge = graph.neighbors(pair[0]).into_iter().find(|(to, _)| *to == pair[1]);
+            total += edge.expect("path uses a missing edge").1;
+        }
+        assert_eq!(total, cost);
+    }
+    // every edge exists with probability 1 / sparsity, no self loops
+    pub fn random_graph(count: usize, graph_type: GraphType, sparsity: u32, weights: Range<isize>) -> Vec<Vec<Option<isize>>> {
+        let mut matrix = vec![vec![None; count]; count];
+        let pairs = (0..count).flat_map(|from| (0..count).map(move |to| (from, to)));
+        for (from, to) in pairs.filter(|(from, to)| from != to) {
+            if thread_rng().gen_ratio(1, sparsity) {
+                let weight = Some(thread_rng().gen_range(weights.clone()));
+                matrix[from][to] = weight;
+                if let GraphType::NDG = graph_type {
+                    matrix[to][from] = weight;
+                }
+            }
+        }
+        matrix
+    }
+}
+
+#[cfg(test)]
+mod test_shortest_path {
+    use std::collections::HashMap;
+    use crate::datastruct::graph::*;
+    use self::adjacency_matrix::GraphAdjacencyMatrix;
+    use self::adjacency_linklist::GraphAdjacencyLinkList;
+    use super::test_util::{check_path, random_graph};
+    fn matrix_of(m: &[[isize; 7]]) -> Vec<Vec<Option<isize>>> {
+        gen_matrix_with_zero_present_nan(m.iter().map(|r| r.to_vec()).collect())
+    }
+    #[test]
+    fn test_path_to() {
+        let matrix = matrix_of(&MATRIX4);
+        let graph = GraphAdjacencyMatrix::from(matrix.clone(), GraphType::NDG);
+        let linklist = GraphAdjacencyLinkList::from(matrix, GraphType::NDG);
+        let (cost, path) = graph.path_to(0, 3).unwrap();
+        assert_eq!(cost, 22);
+        check_path(&graph, 0, 3, cost, &path);
+        assert_eq!(linklist.path_to(0, 3).unwrap().0, 22);
+        assert_eq!(graph.path_to(2, 2), Some((0, vec![2])));
+        let mut matrix = Vec::new();
+        for r in MATRIX1 {
+            matrix.push(r.to_vec());
+        }
+        let graph = GraphAdjacencyMatrix::from(gen_matrix_with_zero_present_nan(matrix.clone()), GraphType::DG);
+        let linklist = GraphAdjacencyLinkList::from(gen_matrix_with_zero_present_nan(matrix), GraphType::DG);
+        assert_eq!(graph.path_to(0, 2), Some((21, vec![0, 1, 3, 2])));
+        assert_eq!(linklist.path_to(0, 2), Some((21, vec![0, 1, 3, 2])));
+        assert_eq!(graph.path_to(3, 0), None);
+        assert_eq!(linklist.path_to(3, 0), None);
+        assert_eq!(graph.path_to(9, 0), None);
+        let tree = linklist.shortest_path_tree_with_dijkstra(0);
+        assert_eq!(tree.predecessor.get(&5), Some(&4));
+        assert_eq!(tree.path_to(5), Some(vec![0, 4, 5]));
+    }
+    #[test]
+    fn test_linklist_distances() {
+        let mut matrix = Vec::new();
+        for r in MATRIX3 {
+            matrix.push(r.to_vec());
+        }
+        let graph = GraphAdjacencyLinkList::from(gen_matrix_with_zero_present_nan(matrix), GraphType::NDG);
+        let path: HashMap<usize, Option<isize>> = vec![(0, Some(0)), (1, Some(6)), (2, Some(11)), (3, Some(4)), (4, Some(10)), (5, Some(16))].into_iter().collect();
+        assert_eq!(graph.shortest_path_with_dijkstra(0), path);
+        let matrix = matrix_of(&MATRIX4);
+        let graph = GraphAdjacencyLinkList::from(matrix.clone(), GraphType::NDG);
+        assert_eq!(graph.shortest_path_with_floyd(), GraphAdjacencyMatrix::from(matrix, GraphType::NDG).shortest_path_with_floyd());
+    }
+    #[test]
+    fn test_dijkstra_indexed() {
+        for graph_type in [GraphType::DG, GraphType::NDG] {
+            let matrix = random_graph(20, graph_type.clone(), 4, 1..20);
+            let graph = GraphAdjacencyMatrix::from(matrix.clone(), graph_type.clone());
+            let linklist = GraphAdjacencyLinkList::from(matrix, graph_type);
+            for from in graph.vertices() {
+                let expected = graph.shortest_path_tree_with_dijkstra(from);
+                for tree in [graph.shortest_path_tree_with_dijkstra_indexed(from), linklist.shortest_path_tree_with_dijkstra_indexed(from)] {
+                    assert_eq!(tree.distance, expected.distance);
+                    for (&to, &cost) in tree.distance.iter() {
+                        check_path(&graph, from, to, cost, &tree.path_to(to).unwrap());
+                    }
+                }
+            }
+        }
+    }
+    #[test]
+    fn test_floyd_next_hop() {
+        for graph_type in [GraphType::DG, GraphType::NDG] {
+            let matrix = random_graph(15, graph_type.clone(), 4, 1..20);
+            let graph = GraphAdjacencyMatrix::from(matrix.clone(), graph_type.clone());
+            let linklist = GraphAdjacencyLinkList::from(matrix, graph_type);
+            let table = graph.shortest_path_table_with_floyd();
+            assert_eq!(table, linklist.shortest_path_table_with_floyd());
+            for from in graph.vertices() {
+                let tree = linklist.shortest_path_tree_with_dijkstra(from);
+                for to in graph.vertices() {
+                    assert_eq!(table.distance(from, to), tree.distance_to(to));
+                    if let Some(cost) = table.distance(from, to) {
+                        check_path(&graph, from, to, cost, &table.path(from, to).unwrap());
+                        check_path(&linklist, from, to, cost, &tree.path_to(to).unwrap());
+                    } else {
+                        assert_eq!(table.path(from, to), None);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_negative_weight {
+    use crate::datastruct::graph::*;
+    use self::adjacency_matrix::GraphAdjacencyMatrix;
+    use self::adjacency_linklist::GraphAdjacencyLinkList;
+    use self::shortest_path::ShortestPathError;
+    use super::test_util::{self, check_path};
+    use rand::{Rng, thread_rng};
+    // shifting non negative weights by vertex potentials gives negative edges but no negative cycle
+    fn random_graph(count: usize) -> Vec<Vec<Option<isize>>> {
+        let potential: Vec<isize> = (0..count).map(|_| thread_rng().gen_range(0..30)).collect();
+        let mut matrix = test_util::random_graph(count, GraphType::DG, 4, 0..20);
+        for (from, row) in matrix.iter_mut().enumerate() {
+            for (to, weight) in row.iter_mut().enumerate() {
+                *weight = weight.map(|w| w + potential[from] - potential[to]);
+            }
+        }
+        matrix
+    }
+    fn check_cycle(graph: &impl GraphTrait, cycle: &[usize]) {
+        assert!(!cycle.is_empty());
+        let mut total = 0;
+        for (i, &from) in cycle.iter().enumerate() {
+            let to = cycle[(i + 1) % cycle.len()];
+            let edge = graph.neighbors(from).into_iter().find(|(id, _)| *id == to);
+            total += edge.expect("cycle uses a missing edge").1;
+        }
+        assert!(total < 0);
+    }
+    #[test]
+    fn test_negative_edges() {
+        for _ in 0..10 {
+            let matrix = random_graph(15);
+            let graph = GraphAdjacencyMatrix::from(matrix.clone(), GraphType::DG);
+            let linklist = GraphAdjacencyLinkList::from(matrix, GraphType::DG);
+            let floyd = graph.shortest_path_table_with_floyd();
+            let johnson = graph.shortest_path_table_with_johnson().unwrap();
+            assert_eq!(johnson.distance, floyd.distance);
+            assert_eq!(linklist.shortest_path_table_with_johnson().unwrap().distance, floyd.distance);
+            for from in graph.vertices() {
+                let bellman_ford = graph.shortest_path_tree_with_bellman_ford(from).unwrap();
+                let spfa = linklist.shortest_path_tree_with_spfa(from).unwrap();
+                assert_eq!(linklist.shortest_path_tree_with_bellman_ford(from).unwrap().distance, bellman_ford.distance);
+                assert_eq!(graph.shortest_path_tree_with_spfa(from).unwrap().distance, spfa.distance);
+                for to in graph.vertices() {
+                    assert_eq!(bellman_ford.distance_to(to), floyd.distance(from, to));
+                    assert_eq!(spfa.distance_to(to), floyd.distance(from, to));
+                    if let Some(cost) = floyd.distance(from, to) {
+                        check_path(&graph, from, to, cost, &bellman_ford.path_to(to).unwrap());
+                        check_path(&linklist, from, to, cost, &spfa.path_to(to).unwrap());
+                        check_path(&graph, from, to, cost, &johnson.path(from, to).unwrap());
+                    }
+                }
+            }
+        }
+    }
+    #[test]
+    fn test_negative_cycle() {
+        let mut graph = GraphAdjacencyMatrix::new(GraphType::DG);
+        let mut linklist = GraphAdjacencyLinkList::new(GraphType::DG);
+        for edge in [(0, 1, 4), (1, 2, -2), (2, 3, 1), (3, 1, -1), (3, 4, 2), (5, 0, 1)] {
+            graph.add_edge(edge);
+            linklist.add_edge(edge);
+        }
+        for cycle in [
+            graph.shortest_path_tree_with_bellman_ford(0).unwrap_err(),
+            graph.shortest_path_tree_with_spfa(0).unwrap_err(),
+            linklist.shortest_path_tree_with_bellman_ford(0).unwrap_err(),
+            linklist.shortest_path_tree_with_spfa(0).unwrap_err(),
+            graph.shortest_path_table_with_johnson().unwrap_err(),
+            linklist.shortest_path_table_with_johnson().unwrap_err(),
+        ] {
+            let ShortestPathError::NegativeCycle(cycle) = cycle;
+            let mut sorted = cycle.clone();
+            sorted.sort();
+            assert_eq!(sorted, vec![1, 2, 3]);
+            check_cycle(&graph, &cycle);
+        }
+        // the cycle cannot be reached from 4, so the distances are fine
+        assert_eq!(graph.shortest_path_tree_with_bellman_ford(4).unwrap().distance_to(4), Some(0));
+        assert_eq!(linklist.shortest_path_tree_with_spfa(4).unwrap().distance_to(0), None);
+        // random graphs with a forced negative cycle
+        for _ in 0..20 {
+            let mut matrix = random_graph(12);
+            let len = thread_rng().gen_range(2..6);
+            for i in 0..len {
+                matrix[i][(i + 1) % len] = Some(-50);
+            }
+            let graph = GraphAdjacencyMatrix::from(matrix.clone(), GraphType::DG);
+            let linklist = GraphAdjacencyLinkList::from(matrix, GraphType::DG);
+            let ShortestPathError::NegativeCycle(cycle) = graph.shortest_path_tree_with_bellman_ford(0).unwrap_err();
+            check_cycle(&graph, &cycle);
+            let ShortestPathError::NegativeCycle(cycle) = linklist.shortest_path_tree_with_spfa(0).unwrap_err();
+            check_cycle(&linklist, &cycle);
+            let ShortestPathError::NegativeCycle(cycle) = graph.shortest_path_table_with_johnson().unwrap_err();
+            check_cycle(&graph, &cycle);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_search {
+    use std::collections::{HashMap, VecDeque};
+    use crate::datastruct::graph::*;
+    use self::adjacency_matrix::GraphAdjacencyMatrix;
+    use self::adjacency_linklist::GraphAdjacencyLinkList;
+    use super::test_util::{check_path, random_graph};
+    fn bfs_depth(graph: &impl GraphTrait, start: usize) -> HashMap<usize, usize> {
+        let mut depth = HashMap::new();
+        let mut queue = VecDeque::new();
+        depth.insert(start, 0);
+        queue.push_back(start);
+        while let Some(id) = queue.pop_front() {
+            for (to, _) in graph.neighbors(id) {
+                if !depth.contains_key(&to) {
+                    depth.insert(to, depth[&id] + 1);
+                    queue.push_back(to);
+                }
+            }
+        }
+        depth
+    }
+    #[test]
+    fn test_grid_a_star() {
+        // a 10 x 10 grid with unit edges, manhattan distance never overestimates
+        let size = 10;
+        let mut graph = GraphAdjacencyLinkList::new(GraphType::NDG);
+        for r in 0..size {
+            for c in 0..size {
+                if c + 1 < size {
+                    graph.add_edge((r * size + c, r * size + c + 1, 1));
+                }
+                if r + 1 < size {
+                    graph.add_edge((r * size + c, (r + 1) * size + c, 1));
+                }
+            }
+        }
+        graph.delete_edge((44, 45));
+        let manhattan = |target: usize| move |id: usize| ((id / size).abs_diff(target / size) + (id % size).abs_diff(target % size)) as isize;
+        let (cost, path) = graph.path_with_a_star(0, 99, manhattan(99)).unwrap();
+        assert_eq!(cost, 18);
+        check_path(&graph, 0, 99, cost, &path);
+        assert_eq!(graph.path_with_a_star(44, 45, manhattan(45)).unwrap().0, 3);
+        assert_eq!(graph.path_with_a_star(7, 7, manhattan(7)), Some((0, vec![7])));
+        assert_eq!(graph.path_with_a_star(0, 100, manhattan(100)), None);
+        let reverse = graph.reverse_index();
+        assert_eq!(reverse.in_neighbors, None);
+        assert_eq!(graph.path_with_bidirectional_dijkstra(&reverse, 0, 99).unwrap().0, 18);
+        assert_eq!(graph.path_with_bidirectional_bfs(&reverse, 0, 99).unwrap().0, 18);
+        assert_eq!(graph.path_with_bidirectional_bfs(&reverse, 0, 100), None);
+    }
+    #[test]
+    fn test_random_search() {
+        for graph_type in [GraphType::DG, GraphType::NDG] {
+            for _ in 0..5 {
+                let matrix = random_graph(30, graph_type.clone(), 8, 0..20);
+                let graph = GraphAdjacencyMatrix::from(matrix.clone(), graph_type.clone());
+                let linklist = GraphAdjacencyLinkList::from(matrix, graph_type.clone());
+                let table = graph.shortest_path_table_with_floyd();
+                let reverse = graph.reverse_index();
+                let linklist_reverse = linklist.reverse_index();
+                for from in graph.vertices() {
+                    let depth = bfs_depth(&graph, from);
+                    for to in graph.vertices() {
+                        let cost = table.distance(from, to);
+                        // an exact heuristic, and the zero heuristic which turns A* into dijkstra
+                        let exact = |id: usize| table.distance(id, to).unwrap_or(0);
+                        for result in [
+                            graph.path_with_a_star(from, to, exact),
+                            linklist.path_with_a_star(from, to, |_| 0),
+                            graph.path_with_bidirectional_dijkstra(&reverse, from, to),
+                            linklist.path_with_bidirectional_dijkstra(&linklist_reverse, from, to),
+                        ] {
+                            assert_eq!(result.as_ref().map(|(cost, _)| *cost), cost);
+                            if let Some((cost, path)) = result {
+                                check_path(&graph, from, to, cost, &path);
+                            }
+                        }
+                        for result in [graph.path_with_bidirectional_bfs(&reverse, from, to), linklist.path_with_bidirectional_bfs(&linklist_reverse, from, to)] {
+                            assert_eq!(result.as_ref().map(|(edges, _)| *edges), depth.get(&to).copied());
+                            if let Some((edges, path)) = result {
+                                assert_eq!(path.len(), edges + 1);
+                                assert_eq!(path.first(), Some(&from));
+                                assert_eq!(path.last(), Some(&to));
+                                assert!(path.windows(2).all(|pair| graph.neighbors(pair[0]).iter().any(|(id, _)| *id == pair[1])));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
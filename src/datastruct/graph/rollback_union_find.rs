@@ -0,0 +1,78 @@
+
+// no path compression so every union can be undone by resetting a single parent
+pub struct RollbackUnionFind {
+    pub parent: Vec<usize>,
+    pub rank: Vec<usize>,
+    pub count: usize,
+    // (child root, parent root, whether the parent rank grew) per successful union
+    pub history: Vec<(usize, usize, bool)>,
+}
+
+impl RollbackUnionFind {
+    pub fn new(size: usize) -> Self {
+        Self {
+            parent: (0..size).collect(),
+            rank: vec![0; size],
+            count: size,
+            history: Vec::new(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+    pub fn find(&self, mut a: usize) -> usize {
+        while self.parent[a] != a {
+            a = self.parent[a];
+        }
+        a
+    }
+    pub fn is_union(&self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+    pub fn component_count(&self) -> usize {
+        self.count
+    }
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let mut pa = self.find(a);
+        let mut pb = self.find(b);
+        if pa == pb {
+            return false;
+        }
+        if self.rank[pa] > self.rank[pb] {
+            std::mem::swap(&mut pa, &mut pb);
+        }
+        let grow = self.rank[pa] == self.rank[pb];
+        self.parent[pa] = pb;
+        if grow {
+            self.rank[pb] += 1;
+        }
+        self.count -= 1;
+        self.history.push((pa, pb, grow));
+        true
+    }
+    // a point to roll back to, unions that did not merge anything leave no trace
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some((child, parent, grow)) => {
+                self.parent[child] = child;
+                if grow {
+                    self.rank[parent] -= 1;
+                }
+                self.count += 1;
+                true
+            }
+            None => false,
+        }
+    }
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+}
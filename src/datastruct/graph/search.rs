@@ -0,0 +1,205 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::datastruct::graph::{GraphTrait, Weight};
+use crate::datastruct::heap::heap::Heap;
+
+// follow the predecessors of id back to the start of the search, start first
+fn trace_back(predecessor: &HashMap<usize, usize>, mut id: usize) -> Vec<usize> {
+    let mut path = vec![id];
+    while let Some(&prev) = predecessor.get(&id) {
+        path.push(prev);
+        id = prev;
+    }
+    path.reverse();
+    path
+}
+
+// in-edges of every vertex for the searches that run backwards from the target
+// build it once and reuse it across queries, it goes stale when the graph changes
+#[derive(Debug, Clone)]
+pub struct ReverseIndex<W: Weight> {
+    // None for an undirected graph, whose neighbors already are its in-edges
+    pub in_neighbors: Option<HashMap<usize, Vec<(usize, W)>>>,
+}
+
+impl<W: Weight> ReverseIndex<W> {
+    pub fn new<V, G: GraphTrait<W, V> + ?Sized>(graph: &G) -> Self {
+        if !graph.is_directed() {
+            return Self {in_neighbors: None};
+        }
+        let mut in_neighbors: HashMap<usize, Vec<(usize, W)>> = HashMap::new();
+        for from in graph.vertices() {
+            for (to, w) in graph.neighbors(from) {
+                in_neighbors.entry(to).or_default().push((from, w));
+            }
+        }
+        Self {in_neighbors: Some(in_neighbors)}
+    }
+    // edges leaving id in the direction of the search
+    fn edges<V, G: GraphTrait<W, V> + ?Sized>(&self, graph: &G, id: usize, is_forward: bool) -> Vec<(usize, W)> {
+        match &self.in_neighbors {
+            Some(in_neighbors) if !is_forward => in_neighbors.get(&id).cloned().unwrap_or_default(),
+            _ => graph.neighbors(id),
+        }
+    }
+}
+
+// the heuristic must not overestimate the remaining cost to target, otherwise the path may not be the shortest
+pub fn a_star<W, V, G, F>(graph: &G, start: usize, target: usize, heuristic: F) -> Option<(W, Vec<usize>)>
+where
+    W: Weight,
+    G: GraphTrait<W, V> + ?Sized,
+    F: Fn(usize) -> W,
+{
+    if !graph.contains_vertex(start) {
+        return None;
+    }
+    let mut distance = HashMap::new();
+    let mut predecessor = HashMap::new();
+    let mut heap = Heap::new();
+    distance.insert(start, W::zero());
+    heap.push((heuristic(start), W::zero(), start));
+    while let Some((_, weight, id)) = heap.pop() {
+        // an entry left behind by a later improvement
+        if distance.get(&id).is_some_and(|&best| best < weight) {
+            continue;
+        }
+        if id == target {
+            return Some((weight, trace_back(&predecessor, target)));
+        }
+        for (to, w) in graph.neighbors(id) {
+            if distance.get(&to).is_none_or(|&old| weight + w < old) {
+                distance.insert(to, weight + w);
+                predecessor.insert(to, id);
+                heap.push((weight + w + heuristic(to), weight + w, to));
+            }
+        }
+    }
+    None
+}
+
+// one side of the bidirectional dijkstra
+struct Frontier<W: Weight> {
+    distance: HashMap<usize, W>,
+    predecessor: HashMap<usize, usize>,
+    visited: HashSet<usize>,
+    heap: Heap<(W, usize)>,
+}
+
+impl<W: Weight> Frontier<W> {
+    fn new(start: usize) -> Self {
+        let mut frontier = Self {
+            distance: HashMap::new(),
+            predecessor: HashMap::new(),
+            visited: HashSet::new(),
+            heap: Heap::new(),
+        };
+        frontier.distance.insert(start, W::zero());
+        frontier.heap.push((W::zero(), start));
+        frontier
+    }
+    fn top(&self) -> Option<W> {
+        self.heap.peek().map(|(weight, _)| *weight)
+    }
+}
+
+// weights must be non negative, like dijkstra, and reverse must be built from this graph
+pub fn bidirectional_dijkstra<W: Weight, V, G: GraphTrait<W, V> + ?Sized>(graph: &G, reverse: &ReverseIndex<W>, start: usize, target: usize) -> Option<(W, Vec<usize>)> {
+    if !graph.contains_vertex(start) || !graph.contains_vertex(target) {
+        return None;
+    }
+    let mut forward: Frontier<W> = Frontier::new(start);
+    let mut backward: Frontier<W> = Frontier::new(target);
+    // best total cost found so far and the vertex where both searches met
+    let mut best: Option<(W, usize)> = if start == target { Some((W::zero(), start)) } else { None };
+    while let (Some(top_forward), Some(top_backward)) = (forward.top(), backward.top()) {
+        if best.is_some_and(|(cost, _)| cost <= top_forward + top_backward) {
+            break;
+        }
+        let is_forward = top_forward <= top_backward;
+        let (this, other) = if is_forward { (&mut forward, &backward) } else { (&mut backward, &forward) };
+        let (weight, id) = this.heap.pop()?;
+        if !this.visited.insert(id) {
+            continue;
+        }
+        for (to, w) in reverse.edges(graph, id, is_forward) {
+            if this.distance.get(&to).is_none_or(|&old| weight + w < old) {
+                this.distance.insert(to, weight + w);
+                this.predecessor.insert(to, id);
+                this.heap.push((weight + w, to));
+            }
+            if let Some(&rest) = other.distance.get(&to) {
+                let cost = this.distance[&to] + rest;
+                if best.is_none_or(|(old, _)| cost < old) {
+                    best = Some((cost, to));
+                }
+            }
+        }
+    }
+    let (cost, meet) = best?;
+    let mut path = trace_back(&forward.predecessor, meet);
+    let mut rest = trace_back(&backward.predecessor, meet);
+    rest.reverse();
+    path.extend(rest.into_iter().skip(1));
+    Some((cost, path))
+}
+
+// fewest edges between start and target, edge weights are ignored
+pub fn bidirectional_bfs<W: Weight, V, G: GraphTrait<W, V> + ?Sized>(graph: &G, reverse: &ReverseIndex<W>, start: usize, target: usize) -> Option<(usize, Vec<usize>)> {
+    if !graph.contains_vertex(start) || !graph.contains_vertex(target) {
+        return None;
+    }
+    if start == target {
+        return Some((0, vec![start]));
+    }
+    // depth and predecessor of every vertex seen from each side
+    let mut forward: HashMap<usize, (usize, Option<usize>)> = HashMap::new();
+    let mut backward: HashMap<usize, (usize, Option<usize>)> = HashMap::new();
+    forward.insert(start, (0, None));
+    backward.insert(target, (0, None));
+    let mut forward_level = vec![start];
+    let mut backward_level = vec![target];
+    while !forward_level.is_empty() && !backward_level.is_empty() {
+        // grow the smaller side by a whole level
+        let is_forward = forward_level.len() <= backward_level.len();
+        let (this, other, level) = if is_forward {
+            (&mut forward, &backward, &mut forward_level)
+        } else {
+            (&mut backward, &forward, &mut backward_level)
+        };
+        let mut best: Option<(usize, usize)> = None;
+        let mut next_level = Vec::new();
+        for &id in level.iter() {
+            let depth = this[&id].0;
+            for (to, _) in reverse.edges(graph, id, is_forward) {
+                if this.contains_key(&to) {
+                    continue;
+                }
+                this.insert(to, (depth + 1, Some(id)));
+                next_level.push(to);
+                if let Some(&(rest, _)) = other.get(&to) {
+                    if best.is_none_or(|(old, _)| depth + 1 + rest < old) {
+                        best = Some((depth + 1 + rest, to));
+                    }
+                }
+            }
+        }
+        if let Some((cost, meet)) = best {
+            let walk = |side: &HashMap<usize, (usize, Option<usize>)>| {
+                let mut path = vec![meet];
+                let mut cur = meet;
+                while let Some(prev) = side[&cur].1 {
+                    path.push(prev);
+                    cur = prev;
+                }
+                path
+            };
+            let mut path = walk(&forward);
+            path.reverse();
+            path.extend(walk(&backward).into_iter().skip(1));
+            return Some((cost, path));
+        }
+        *level = next_level;
+    }
+    None
+}
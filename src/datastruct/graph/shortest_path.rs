@@ -0,0 +1,351 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::Sub;
+
+use crate::datastruct::graph::{GraphTrait, Weight};
+use crate::datastruct::heap::heap::{Heap, HeapOrder};
+use crate::datastruct::heap::indexed_heap::IndexedHeap;
+
+#[derive(Debug, PartialEq)]
+pub enum ShortestPathError {
+    // the vertices of a negative cycle in path order
+    NegativeCycle(Vec<usize>),
+}
+
+// distances and predecessors of every vertex reachable from start
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortestPathTree<W: Weight> {
+    pub start: usize,
+    pub distance: HashMap<usize, W>,
+    pub predecessor: HashMap<usize, usize>,
+}
+
+// next_hop[from][to] is the vertex following from on a shortest path to to
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllPairsShortestPath<W: Weight> {
+    pub distance: HashMap<usize, HashMap<usize, W>>,
+    pub next_hop: HashMap<usize, HashMap<usize, usize>>,
+}
+
+impl<W: Weight> ShortestPathTree<W> {
+    pub fn distance_to(&self, target: usize) -> Option<W> {
+        self.distance.get(&target).copied()
+    }
+    // vertices from start to target, both included
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        if !self.distance.contains_key(&target) {
+            return None;
+        }
+        let mut path = vec![target];
+        let mut cur = target;
+        while cur != self.start {
+            cur = *self.predecessor.get(&cur)?;
+            path.push(cur);
+            // a predecessor loop only comes from a negative cycle
+            if path.len() > self.distance.len() {
+                return None;
+            }
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<W: Weight> AllPairsShortestPath<W> {
+    pub fn distance(&self, from: usize, to: usize) -> Option<W> {
+        self.distance.get(&from)?.get(&to).copied()
+    }
+    pub fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        self.distance(from, to)?;
+        let mut path = vec![from];
+        let mut cur = from;
+        while cur != to {
+            cur = *self.next_hop.get(&cur)?.get(&to)?;
+            path.push(cur);
+            if path.len() > self.distance.len() {
+                return None;
+            }
+        }
+        Some(path)
+    }
+}
+
+pub fn dijkstra<W: Weight, V, G: GraphTrait<W, V> + ?Sized>(graph: &G, start: usize) -> ShortestPathTree<W> {
+    if !graph.vertices().contains(&start) {
+        return ShortestPathTree {start, distance: HashMap::new(), predecessor: HashMap::new()};
+    }
+    dijkstra_with(start, |id| graph.neighbors(id))
+}
+
+// every vertex is in the heap at most once and its key is lowered in place, no stale entries to skip
+pub fn dijkstra_indexed<W: Weight, V, G: GraphTrait<W, V> + ?Sized>(graph: &G, start: usize) -> ShortestPathTree<W> {
+    let mut tree = ShortestPathTree {
+        start,
+        distance: HashMap::new(),
+        predecessor: HashMap::new(),
+    };
+    if !graph.contains_vertex(start) {
+        return tree;
+    }
+    let ids = graph.vertices();
+    let index: HashMap<usize, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+    let mut vistied = vec![false; ids.len()];
+    let mut heap = IndexedHeap::new(HeapOrder::MIN);
+    heap.push(index[&start], W::zero());
+    while let Some((cur, weight)) = heap.pop() {
+        vistied[cur] = true;
+        tree.distance.insert(ids[cur], weight);
+        for (to_id, w) in graph.neighbors(ids[cur]) {
+            let to = index[&to_id];
+            if vistied[to] {
+                continue;
+            }
+            if heap.decrease_key(to, weight + w) || heap.push(to, weight + w) {
+                tree.predecessor.insert(to_id, ids[cur]);
+            }
+        }
+    }
+    tree
+}
+
+fn dijkstra_with<W: Weight>(start: usize, neighbors: impl Fn(usize) -> Vec<(usize, W)>) -> ShortestPathTree<W> {
+    let mut tree = ShortestPathTree {
+        start,
+        distance: HashMap::new(),
+        predecessor: HashMap::new(),
+    };
+    let mut vistied = HashSet::new();
+    let mut heap = Heap::new();
+    heap.push((W::zero(), start));
+    tree.distance.insert(start, W::zero());
+    while let Some((weight, id)) = heap.pop() {
+        if !vistied.insert(id) {
+            continue;
+        }
+        for (to, w) in neighbors(id) {
+            let better = match tree.distance.get(&to) {
+                Some(old_weight) => weight + w < *old_weight,
+                None => true,
+            };
+            if better {
+                tree.distance.insert(to, weight + w);
+                tree.predecessor.insert(to, id);
+                heap.push((weight + w, to));
+            }
+        }
+    }
+    tree
+}
+
+pub fn floyd<W: Weight, V, G: GraphTrait<W, V> + ?Sized>(graph: &G) -> AllPairsShortestPath<W> {
+    let ids = graph.vertices();
+    let count = ids.len();
+    let index: HashMap<usize, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+    let mut distance = vec![vec![None; count]; count];
+    let mut next = vec![vec![None; count]; count];
+    for (from, id) in ids.iter().enumerate() {
+        distance[from][from] = Some(W::zero());
+        next[from][from] = Some(from);
+        for (to_id, w) in graph.neighbors(*id) {
+            let to = index[&to_id];
+            if from != to && distance[from][to].is_none_or(|old| w < old) {
+                distance[from][to] = Some(w);
+                next[from][to] = Some(to);
+            }
+        }
+    }
+    for transfer in 0..count {
+        for from in 0..count {
+            let w_f_trans = match distance[from][transfer] {
+                Some(w) => w,
+                None => continue,
+            };
+            for to in 0..count {
+                if let Some(w_trans_t) = distance[transfer][to] {
+                    if distance[from][to].is_none_or(|w_f_t| w_f_trans + w_trans_t < w_f_t) {
+                        distance[from][to] = Some(w_f_trans + w_trans_t);
+                        next[from][to] = next[from][transfer];
+                    }
+                }
+            }
+        }
+    }
+    let mut table = AllPairsShortestPath {
+        distance: HashMap::new(),
+        next_hop: HashMap::new(),
+    };
+    for from in 0..count {
+        let mut distance_map = HashMap::new();
+        let mut next_map = HashMap::new();
+        for to in 0..count {
+            if let (Some(w), Some(hop)) = (distance[from][to], next[from][to]) {
+                distance_map.insert(ids[to], w);
+                next_map.insert(ids[to], ids[hop]);
+            }
+        }
+        table.distance.insert(ids[from], distance_map);
+        table.next_hop.insert(ids[from], next_map);
+    }
+    table
+}
+
+fn edges<W: Weight, V, G: GraphTrait<W, V> + ?Sized>(graph: &G) -> Vec<(usize, usize, W)> {
+    let mut edges = Vec::new();
+    for from in graph.vertices() {
+        for (to, w) in graph.neighbors(from) {
+            edges.push((from, to, w));
+        }
+    }
+    edges
+}
+
+// a cycle in the predecessor graph, the walks begin at the given vertices
+fn predecessor_cycle(predecessor: &HashMap<usize, usize>, starts: impl Iterator<Item = usize>) -> Option<Vec<usize>> {
+    let mut done = HashSet::new();
+    for start in starts {
+        let mut walk = Vec::new();
+        let mut on_walk = HashMap::new();
+        let mut cur = start;
+        loop {
+            if done.contains(&cur) {
+                break;
+            }
+            if let Some(&position) = on_walk.get(&cur) {
+                let mut cycle: Vec<usize> = walk[position..].to_vec();
+                // the walk follows predecessors, so reverse it into path order
+                cycle.reverse();
+                return Some(cycle);
+            }
+            on_walk.insert(cur, walk.len());
+            walk.push(cur);
+            match predecessor.get(&cur) {
+                Some(&next) => cur = next,
+                None => break,
+            }
+        }
+        done.extend(walk);
+    }
+    None
+}
+
+// relax every edge count - 1 times, a change in one more round means a negative cycle
+fn relax_rounds<W: Weight>(count: usize, edges: &[(usize, usize, W)], distance: &mut HashMap<usize, W>, predecessor: &mut HashMap<usize, usize>) -> Result<(), ShortestPathError> {
+    for round in 0..count {
+        let mut changed = None;
+        for &(from, to, w) in edges.iter() {
+            if let Some(&weight) = distance.get(&from) {
+                if distance.get(&to).is_none_or(|&old| weight + w < old) {
+                    distance.insert(to, weight + w);
+                    predecessor.insert(to, from);
+                    changed = Some(to);
+                }
+            }
+        }
+        match changed {
+            None => return Ok(()),
+            Some(to) if round + 1 == count => {
+                let cycle = predecessor_cycle(predecessor, std::iter::once(to).chain(predecessor.keys().cloned()));
+                return Err(ShortestPathError::NegativeCycle(cycle.unwrap_or_default()));
+            }
+            Some(_) => (),
+        }
+    }
+    Ok(())
+}
+
+pub fn bellman_ford<W: Weight, V, G: GraphTrait<W, V> + ?Sized>(graph: &G, start: usize) -> Result<ShortestPathTree<W>, ShortestPathError> {
+    let mut tree = ShortestPathTree {
+        start,
+        distance: HashMap::new(),
+        predecessor: HashMap::new(),
+    };
+    let ids = graph.vertices();
+    if !ids.contains(&start) {
+        return Ok(tree);
+    }
+    tree.distance.insert(start, W::zero());
+    relax_rounds(ids.len(), &edges(graph), &mut tree.distance, &mut tree.predecessor)?;
+    Ok(tree)
+}
+
+// queue based Bellman-Ford, a path of count edges means the predecessors went round a cycle
+pub fn spfa<W: Weight, V, G: GraphTrait<W, V> + ?Sized>(graph: &G, start: usize) -> Result<ShortestPathTree<W>, ShortestPathError> {
+    let mut tree = ShortestPathTree {
+        start,
+        distance: HashMap::new(),
+        predecessor: HashMap::new(),
+    };
+    let count = graph.vertices().len();
+    if !graph.vertices().contains(&start) {
+        return Ok(tree);
+    }
+    let mut queue = VecDeque::new();
+    let mut in_queue = HashSet::new();
+    let mut edge_count: HashMap<usize, usize> = HashMap::new();
+    tree.distance.insert(start, W::zero());
+    queue.push_back(start);
+    in_queue.insert(start);
+    while let Some(from) = queue.pop_front() {
+        in_queue.remove(&from);
+        let weight = tree.distance[&from];
+        for (to, w) in graph.neighbors(from) {
+            if tree.distance.get(&to).is_none_or(|&old| weight + w < old) {
+                tree.distance.insert(to, weight + w);
+                tree.predecessor.insert(to, from);
+                let edges = edge_count.get(&from).copied().unwrap_or(0) + 1;
+                edge_count.insert(to, edges);
+                if edges >= count {
+                    let starts = std::iter::once(to).chain(tree.predecessor.keys().cloned());
+                    if let Some(cycle) = predecessor_cycle(&tree.predecessor, starts) {
+                        return Err(ShortestPathError::NegativeCycle(cycle));
+                    }
+                }
+                if in_queue.insert(to) {
+                    queue.push_back(to);
+                }
+            }
+        }
+    }
+    Ok(tree)
+}
+
+// reweight with Bellman-Ford potentials so that every edge is non negative, then run Dijkstra from each vertex
+pub fn johnson<W, V, G>(graph: &G) -> Result<AllPairsShortestPath<W>, ShortestPathError>
+where
+    W: Weight + Sub<Output = W>,
+    G: GraphTrait<W, V> + ?Sized,
+{
+    let ids = graph.vertices();
+    let edges = edges(graph);
+    // starting every vertex at zero stands for an extra source joined to all of them
+    let mut potential: HashMap<usize, W> = ids.iter().map(|&id| (id, W::zero())).collect();
+    relax_rounds(ids.len() + 1, &edges, &mut potential, &mut HashMap::new())?;
+    let mut table = AllPairsShortestPath {
+        distance: HashMap::new(),
+        next_hop: HashMap::new(),
+    };
+    for &from in ids.iter() {
+        let tree = dijkstra_with(from, |id| {
+            graph.neighbors(id)
+                .into_iter()
+                .map(|(to, w)| (to, w + potential[&id] - potential[&to]))
+                .collect()
+        });
+        let mut distance_map = HashMap::new();
+        let mut next_map = HashMap::new();
+        for (&to, &w) in tree.distance.iter() {
+            distance_map.insert(to, w - potential[&from] + potential[&to]);
+            // walk back from to until the vertex right after from
+            let mut hop = to;
+            while let Some(&prev) = tree.predecessor.get(&hop) {
+                if prev == from {
+                    break;
+                }
+                hop = prev;
+            }
+            next_map.insert(to, hop);
+        }
+        table.distance.insert(from, distance_map);
+        table.next_hop.insert(from, next_map);
+    }
+    Ok(table)
+}
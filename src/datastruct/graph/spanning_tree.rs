@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use crate::datastruct::graph::{GraphTrait, Weight};
+use crate::datastruct::heap::heap::HeapOrder;
+use crate::datastruct::heap::indexed_heap::IndexedHeap;
+
+// edges of a minimum spanning forest, every vertex is in the heap once and its key is lowered in place
+// a tree grows from the smallest id left, so a disconnected graph gets one tree per component
+pub fn prim_indexed<W: Weight, V, G: GraphTrait<W, V> + ?Sized>(graph: &G) -> Vec<(usize, usize, W)> {
+    let ids = graph.vertices();
+    let index: HashMap<usize, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+    let mut vistied = vec![false; ids.len()];
+    let mut parent = vec![None; ids.len()];
+    let mut heap = IndexedHeap::new(HeapOrder::MIN);
+    let mut edges = Vec::new();
+    for root in 0..ids.len() {
+        if vistied[root] {
+            continue;
+        }
+        heap.push(root, W::zero());
+        while let Some((cur, weight)) = heap.pop() {
+            vistied[cur] = true;
+            if let Some(from) = parent[cur] {
+                edges.push((ids[from], ids[cur], weight));
+            }
+            for (to_id, w) in graph.neighbors(ids[cur]) {
+                let to = index[&to_id];
+                if vistied[to] {
+                    continue;
+                }
+                if heap.decrease_key(to, w) || heap.push(to, w) {
+                    parent[to] = Some(cur);
+                }
+            }
+        }
+    }
+    edges
+}
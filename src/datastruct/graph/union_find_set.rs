@@ -1,22 +1,51 @@
 
+// size[i] is only meaningful while i is a root
 pub struct UnionFindSet {
     pub parent: Vec<usize>,
+    pub size: Vec<usize>,
+    pub count: usize,
 }
 
 impl UnionFindSet {
     pub fn new(size: usize) -> Self {
         let mut unf = Self {
             parent: Vec::new(),
+            size: Vec::new(),
+            count: 0,
         };
-        for i in 0..size {
-            unf.parent.push(i);
+        for _ in 0..size {
+            unf.add_element();
         }
         unf
     }
-    pub fn union(&mut self, a: usize, b: usize) {
-        let pa = self.find(a);
-        let pb = self.find(b);
+    // the new element starts in a component of its own, its index is returned
+    pub fn add_element(&mut self) -> usize {
+        let index = self.parent.len();
+        self.parent.push(index);
+        self.size.push(1);
+        self.count += 1;
+        index
+    }
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+    // the smaller tree is hung below the larger one, false if a and b were already joined
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let mut pa = self.find(a);
+        let mut pb = self.find(b);
+        if pa == pb {
+            return false;
+        }
+        if self.size[pa] > self.size[pb] {
+            std::mem::swap(&mut pa, &mut pb);
+        }
         self.parent[pa] = pb;
+        self.size[pb] += self.size[pa];
+        self.count -= 1;
+        true
     }
     pub fn find(&mut self, a: usize) -> usize {
         if self.parent[a] != a {
@@ -28,4 +57,25 @@ impl UnionFindSet {
     pub fn is_union(&mut self, a: usize, b: usize) -> bool {
         self.find(a) == self.find(b)
     }
+    pub fn component_count(&self) -> usize {
+        self.count
+    }
+    pub fn size_of(&mut self, a: usize) -> usize {
+        let pa = self.find(a);
+        self.size[pa]
+    }
+    // members in increasing order, components ordered by their smallest member
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut group_of = vec![None; self.parent.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.count);
+        for i in 0..self.parent.len() {
+            let root = self.find(i);
+            let group = *group_of[root].get_or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(i);
+        }
+        groups
+    }
 }
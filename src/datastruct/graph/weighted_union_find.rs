@@ -0,0 +1,73 @@
+use std::ops::{Add, Sub};
+
+#[derive(Debug, PartialEq)]
+pub enum WeightedUnionError<T> {
+    // the difference already implied between the two elements
+    Contradiction(T),
+}
+
+// weight[i] is potential(i) - potential(parent[i]), T::default() is the zero
+pub struct WeightedUnionFind<T> {
+    pub parent: Vec<usize>,
+    pub rank: Vec<usize>,
+    pub weight: Vec<T>,
+}
+
+impl<T> WeightedUnionFind<T>
+where
+    T: Copy + PartialEq + Default + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new(size: usize) -> Self {
+        Self {
+            parent: (0..size).collect(),
+            rank: vec![0; size],
+            weight: vec![T::default(); size],
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+    // the root of a and the potential of a relative to it
+    pub fn find(&mut self, a: usize) -> (usize, T) {
+        if self.parent[a] == a {
+            return (a, T::default());
+        }
+        let (root, potential) = self.find(self.parent[a]);
+        self.weight[a] = self.weight[a] + potential;
+        self.parent[a] = root;
+        (root, self.weight[a])
+    }
+    pub fn is_union(&mut self, a: usize, b: usize) -> bool {
+        self.find(a).0 == self.find(b).0
+    }
+    // a - b, if it is known
+    pub fn diff(&mut self, a: usize, b: usize) -> Option<T> {
+        let (ra, pa) = self.find(a);
+        let (rb, pb) = self.find(b);
+        if ra == rb {Some(pa - pb)} else {None}
+    }
+    // record a - b = d, false if it was already implied
+    pub fn union(&mut self, a: usize, b: usize, d: T) -> Result<bool, WeightedUnionError<T>> {
+        let (ra, pa) = self.find(a);
+        let (rb, pb) = self.find(b);
+        if ra == rb {
+            return if pa - pb == d {Ok(false)} else {Err(WeightedUnionError::Contradiction(pa - pb))};
+        }
+        // potential(ra) - potential(rb) so that a - b comes out as d
+        let offset = d - pa + pb;
+        if self.rank[ra] < self.rank[rb] {
+            self.parent[ra] = rb;
+            self.weight[ra] = offset;
+        } else {
+            self.parent[rb] = ra;
+            self.weight[rb] = T::default() - offset;
+            if self.rank[ra] == self.rank[rb] {
+                self.rank[ra] += 1;
+            }
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,110 @@
+use crate::datastruct::heap::PriorityQueue;
+
+#[derive(Debug, Clone)]
+pub struct BinomialNode<T: PartialOrd> {
+    pub data: T,
+    // the child at index i is the root of a tree of order i
+    pub children: Vec<Box<BinomialNode<T>>>,
+}
+
+// trees[i] holds the tree of order i, if any
+#[derive(Debug, Clone)]
+pub struct BinomialHeap<T: PartialOrd> {
+    pub trees: Vec<Option<Box<BinomialNode<T>>>>,
+    pub len: usize,
+}
+
+impl<T: PartialOrd> BinomialHeap<T> {
+    pub fn new() -> Self {
+        Self {
+            trees: Vec::new(),
+            len: 0,
+        }
+    }
+    fn link(mut a: Box<BinomialNode<T>>, mut b: Box<BinomialNode<T>>) -> Box<BinomialNode<T>> {
+        if b.data < a.data {
+            std::mem::swap(&mut a, &mut b);
+        }
+        a.children.push(b);
+        a
+    }
+    fn meld_trees(&mut self, trees: Vec<Option<Box<BinomialNode<T>>>>) {
+        let mut carry: Option<Box<BinomialNode<T>>> = None;
+        let mut other = trees.into_iter();
+        let mut order = 0;
+        loop {
+            let incoming = other.next();
+            if incoming.is_none() && carry.is_none() && order >= self.trees.len() {
+                break;
+            }
+            if order == self.trees.len() {
+                self.trees.push(None);
+            }
+            let mut present: Vec<Box<BinomialNode<T>>> = Vec::new();
+            present.extend(self.trees[order].take());
+            present.extend(incoming.flatten());
+            present.extend(carry.take());
+            match present.len() {
+                0 => (),
+                1 => self.trees[order] = present.pop(),
+                _ => {
+                    let a = present.pop();
+                    let b = present.pop();
+                    if let (Some(a), Some(b)) = (a, b) {
+                        carry = Some(Self::link(a, b));
+                    }
+                    self.trees[order] = present.pop();
+                }
+            }
+            order += 1;
+        }
+        while let Some(None) = self.trees.last() {
+            self.trees.pop();
+        }
+    }
+    fn min_order(&self) -> Option<usize> {
+        let mut min: Option<usize> = None;
+        for (order, tree) in self.trees.iter().enumerate() {
+            if let Some(tree) = tree {
+                match min.and_then(|m| self.trees[m].as_ref()) {
+                    Some(current) if current.data <= tree.data => (),
+                    _ => min = Some(order),
+                }
+            }
+        }
+        min
+    }
+}
+
+impl<T: PartialOrd> Default for BinomialHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialOrd> PriorityQueue<T> for BinomialHeap<T> {
+    type Peek<'a> = &'a T where T: 'a;
+    fn push(&mut self, data: T) {
+        self.meld_trees(vec![Some(Box::new(BinomialNode {data, children: Vec::new()}))]);
+        self.len += 1;
+    }
+    fn pop(&mut self) -> Option<T> {
+        let order = self.min_order()?;
+        let tree = self.trees[order].take()?;
+        let BinomialNode {data, children} = *tree;
+        self.meld_trees(children.into_iter().map(Some).collect());
+        self.len -= 1;
+        Some(data)
+    }
+    fn peek(&self) -> Option<&T> {
+        let order = self.min_order()?;
+        self.trees[order].as_ref().map(|tree| &tree.data)
+    }
+    fn meld(&mut self, mut other: Self) {
+        self.len += other.len;
+        self.meld_trees(std::mem::take(&mut other.trees));
+    }
+    fn len(&self) -> usize {
+        self.len
+    }
+}
@@ -0,0 +1,148 @@
+use std::cmp::Ordering;
+use std::ops::{Deref, DerefMut};
+
+use crate::datastruct::heap::heap::{sift_down, sift_up};
+
+pub type Comparator<T> = Box<dyn Fn(&T, &T) -> Ordering>;
+
+// the element that compares Less than every other one sits on the top
+pub struct ComparatorHeap<T> {
+    pub heap: Vec<T>,
+    compare: Comparator<T>,
+}
+
+pub struct PeekMut<'a, T> {
+    heap: &'a mut ComparatorHeap<T>,
+}
+
+pub struct IntoIterSorted<T> {
+    heap: ComparatorHeap<T>,
+}
+
+impl<T> ComparatorHeap<T> {
+    pub fn new(compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        Self {
+            heap: Vec::new(),
+            compare: Box::new(compare),
+        }
+    }
+    pub fn by_key<K: Ord>(key: impl Fn(&T) -> K + 'static) -> Self {
+        Self::new(move |a, b| key(a).cmp(&key(b)))
+    }
+    pub fn from_vec(vec: Vec<T>, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        let mut heap = Self {
+            heap: vec,
+            compare: Box::new(compare),
+        };
+        heap.heapify();
+        heap
+    }
+    pub fn push(&mut self, data: T) {
+        self.heap.push(data);
+        let last = self.heap.len() - 1;
+        let compare = &self.compare;
+        sift_up(&mut self.heap, last, |a, b| compare(a, b) == Ordering::Less);
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        if self.heap.is_empty() {return None;}
+        let last = self.heap.len() - 1;
+        self.heap.swap(0, last);
+        let data = self.heap.pop();
+        self.sift_down(0);
+        data
+    }
+    pub fn peek(&self) -> Option<&T> {
+        self.heap.first()
+    }
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T>> {
+        if self.heap.is_empty() {
+            None
+        } else {
+            Some(PeekMut {heap: self})
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.heap.iter()
+    }
+    pub fn into_vec(self) -> Vec<T> {
+        self.heap
+    }
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut sorted = Vec::with_capacity(self.heap.len());
+        while let Some(data) = self.pop() {
+            sorted.push(data);
+        }
+        sorted
+    }
+    pub fn into_iter_sorted(self) -> IntoIterSorted<T> {
+        IntoIterSorted {heap: self}
+    }
+    fn heapify(&mut self) {
+        for index in (0..self.heap.len() / 2).rev() {
+            self.sift_down(index);
+        }
+    }
+    fn sift_down(&mut self, index: usize) {
+        let compare = &self.compare;
+        sift_down(&mut self.heap, index, |a, b| compare(a, b) == Ordering::Less);
+    }
+}
+
+impl<T> Deref for PeekMut<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.heap.heap[0]
+    }
+}
+
+impl<T> DerefMut for PeekMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.heap.heap[0]
+    }
+}
+
+impl<T> Drop for PeekMut<'_, T> {
+    fn drop(&mut self) {
+        self.heap.sift_down(0);
+    }
+}
+
+impl<T> PeekMut<'_, T> {
+    pub fn pop(self) -> T {
+        let mut this = std::mem::ManuallyDrop::new(self);
+        match this.heap.pop() {
+            Some(data) => data,
+            None => unreachable!("peek_mut is only handed out for a non-empty heap"),
+        }
+    }
+}
+
+impl<T> Iterator for IntoIterSorted<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        self.heap.pop()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.heap.len(), Some(self.heap.len()))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ComparatorHeap<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.heap.iter()
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for ComparatorHeap<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComparatorHeap").field("heap", &self.heap).finish()
+    }
+}
@@ -0,0 +1,112 @@
+use crate::datastruct::heap::PriorityQueue;
+use crate::datastruct::heap::heap::HeapOrder;
+
+// the children of node i are i * arity + 1 ..= i * arity + arity
+#[derive(Debug, Clone)]
+pub struct DaryHeap<T: PartialOrd> {
+    pub heap: Vec<T>,
+    pub arity: usize,
+    pub order: HeapOrder,
+}
+
+impl<T: PartialOrd> DaryHeap<T> {
+    pub fn new(arity: usize) -> Self {
+        Self::with_order(arity, HeapOrder::MIN)
+    }
+    pub fn with_order(arity: usize, order: HeapOrder) -> Self {
+        assert!(arity >= 2, "d-ary heap arity must be at least 2");
+        Self {
+            heap: Vec::new(),
+            arity,
+            order,
+        }
+    }
+    pub fn from_vec(vec: Vec<T>, arity: usize, order: HeapOrder) -> Self {
+        let mut heap = Self::with_order(arity, order);
+        heap.heap = vec;
+        if heap.heap.len() > 1 {
+            for index in (0..=(heap.heap.len() - 2) / arity).rev() {
+                heap.sift_down(index);
+            }
+        }
+        heap
+    }
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    pub fn peek(&self) -> Option<&T> {
+        self.heap.first()
+    }
+    pub fn push(&mut self, data: T) {
+        self.heap.push(data);
+        let mut cur = self.heap.len() - 1;
+        while cur != 0 {
+            let parent = (cur - 1) / self.arity;
+            if self.before(cur, parent) {
+                self.heap.swap(cur, parent);
+                cur = parent;
+            } else {
+                break;
+            }
+        }
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        if self.heap.is_empty() {return None;}
+        let last = self.heap.len() - 1;
+        self.heap.swap(0, last);
+        let data = self.heap.pop();
+        self.sift_down(0);
+        data
+    }
+    fn before(&self, a: usize, b: usize) -> bool {
+        match self.order {
+            HeapOrder::MIN => self.heap[a] < self.heap[b],
+            HeapOrder::MAX => self.heap[a] > self.heap[b],
+        }
+    }
+    fn sift_down(&mut self, mut cur: usize) {
+        let len = self.heap.len();
+        loop {
+            let first_child = cur * self.arity + 1;
+            if first_child >= len {
+                break;
+            }
+            let mut best = first_child;
+            for child in first_child + 1..usize::min(first_child + self.arity, len) {
+                if self.before(child, best) {
+                    best = child;
+                }
+            }
+            if self.before(best, cur) {
+                self.heap.swap(best, cur);
+                cur = best;
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+impl<T: PartialOrd> PriorityQueue<T> for DaryHeap<T> {
+    type Peek<'a> = &'a T where T: 'a;
+    fn push(&mut self, data: T) {
+        DaryHeap::push(self, data);
+    }
+    fn pop(&mut self) -> Option<T> {
+        DaryHeap::pop(self)
+    }
+    fn peek(&self) -> Option<&T> {
+        self.heap.first()
+    }
+    fn meld(&mut self, other: Self) {
+        for data in other.heap {
+            DaryHeap::push(self, data);
+        }
+    }
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
@@ -0,0 +1,215 @@
+use std::cell::{Ref, RefCell};
+use std::rc::{Rc, Weak};
+
+use crate::datastruct::heap::{DecreaseKey, PriorityQueue};
+
+#[derive(Debug)]
+pub struct FibonacciNode<T: PartialOrd> {
+    pub data: T,
+    pub mark: bool,
+    pub parent: Weak<RefCell<FibonacciNode<T>>>,
+    // position in the children of the parent, or in the roots for a root
+    pub index: usize,
+    pub children: Vec<Rc<RefCell<FibonacciNode<T>>>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FibonacciHandle<T: PartialOrd>(Weak<RefCell<FibonacciNode<T>>>);
+
+#[derive(Debug)]
+pub struct FibonacciHeap<T: PartialOrd> {
+    pub roots: Vec<Rc<RefCell<FibonacciNode<T>>>>,
+    pub min: Option<usize>,
+    pub len: usize,
+}
+
+impl<T: PartialOrd> FibonacciHeap<T> {
+    pub fn new() -> Self {
+        Self {
+            roots: Vec::new(),
+            min: None,
+            len: 0,
+        }
+    }
+    fn add_root(&mut self, node: Rc<RefCell<FibonacciNode<T>>>) {
+        let is_min = match self.min {
+            Some(min) => node.borrow().data < self.roots[min].borrow().data,
+            None => true,
+        };
+        node.borrow_mut().index = self.roots.len();
+        self.roots.push(node);
+        if is_min {
+            self.min = Some(self.roots.len() - 1);
+        }
+    }
+    fn link(a: Rc<RefCell<FibonacciNode<T>>>, b: Rc<RefCell<FibonacciNode<T>>>) -> Rc<RefCell<FibonacciNode<T>>> {
+        let (parent, child) = if b.borrow().data < a.borrow().data {(b, a)} else {(a, b)};
+        let index = parent.borrow().children.len();
+        {
+            let mut child_node = child.borrow_mut();
+            child_node.parent = Rc::downgrade(&parent);
+            child_node.index = index;
+            child_node.mark = false;
+        }
+        parent.borrow_mut().children.push(child);
+        parent
+    }
+    // link roots of equal degree until every degree appears at most once
+    fn consolidate(&mut self) {
+        let mut table: Vec<Option<Rc<RefCell<FibonacciNode<T>>>>> = Vec::new();
+        for mut root in std::mem::take(&mut self.roots) {
+            let mut degree = root.borrow().children.len();
+            loop {
+                if degree >= table.len() {
+                    table.resize(degree + 1, None);
+                }
+                match table[degree].take() {
+                    Some(other) => {
+                        root = Self::link(root, other);
+                        degree += 1;
+                    }
+                    None => {
+                        table[degree] = Some(root);
+                        break;
+                    }
+                }
+            }
+        }
+        self.min = None;
+        for root in table.into_iter().flatten() {
+            self.add_root(root);
+        }
+    }
+    fn cut(&mut self, node: Rc<RefCell<FibonacciNode<T>>>, parent: &Rc<RefCell<FibonacciNode<T>>>) {
+        let index = node.borrow().index;
+        {
+            // the last child takes the place of the cut one
+            let mut parent_node = parent.borrow_mut();
+            parent_node.children.swap_remove(index);
+            if let Some(moved) = parent_node.children.get(index) {
+                moved.borrow_mut().index = index;
+            }
+        }
+        {
+            let mut child_node = node.borrow_mut();
+            child_node.parent = Weak::new();
+            child_node.mark = false;
+        }
+        self.add_root(node);
+    }
+    fn cascading_cut(&mut self, mut node: Rc<RefCell<FibonacciNode<T>>>) {
+        loop {
+            let parent = match node.borrow().parent.upgrade() {
+                Some(parent) => parent,
+                None => return,
+            };
+            if !node.borrow().mark {
+                node.borrow_mut().mark = true;
+                return;
+            }
+            self.cut(node, &parent);
+            node = parent;
+        }
+    }
+}
+
+impl<T: PartialOrd> Default for FibonacciHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// a long chain of single children would otherwise be dropped recursively
+impl<T: PartialOrd> Drop for FibonacciHeap<T> {
+    fn drop(&mut self) {
+        let mut stack = std::mem::take(&mut self.roots);
+        while let Some(node) = stack.pop() {
+            stack.append(&mut node.borrow_mut().children);
+        }
+    }
+}
+
+impl<T: PartialOrd> PriorityQueue<T> for FibonacciHeap<T> {
+    type Peek<'a> = Ref<'a, T> where T: 'a;
+    fn push(&mut self, data: T) {
+        self.push_with_handle(data);
+    }
+    fn pop(&mut self) -> Option<T> {
+        let min = self.min?;
+        let node = self.roots.swap_remove(min);
+        let children = std::mem::take(&mut node.borrow_mut().children);
+        for child in children {
+            {
+                let mut child_node = child.borrow_mut();
+                child_node.parent = Weak::new();
+                child_node.mark = false;
+            }
+            self.roots.push(child);
+        }
+        self.consolidate();
+        self.len -= 1;
+        match Rc::try_unwrap(node) {
+            Ok(node) => Some(node.into_inner().data),
+            Err(_) => panic!("fibonacci heap node is still shared"),
+        }
+    }
+    fn peek(&self) -> Option<Ref<'_, T>> {
+        self.min.map(|min| Ref::map(self.roots[min].borrow(), |node| &node.data))
+    }
+    fn meld(&mut self, mut other: Self) {
+        self.len += other.len;
+        other.len = 0;
+        other.min = None;
+        for root in std::mem::take(&mut other.roots) {
+            self.add_root(root);
+        }
+    }
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T: PartialOrd> DecreaseKey<T> for FibonacciHeap<T> {
+    type Handle = FibonacciHandle<T>;
+    fn push_with_handle(&mut self, data: T) -> FibonacciHandle<T> {
+        let node = Rc::new(RefCell::new(FibonacciNode {
+            data,
+            mark: false,
+            parent: Weak::new(),
+            index: 0,
+            children: Vec::new(),
+        }));
+        let handle = FibonacciHandle(Rc::downgrade(&node));
+        self.add_root(node);
+        self.len += 1;
+        handle
+    }
+    fn decrease_key(&mut self, handle: &FibonacciHandle<T>, data: T) -> bool {
+        let node = match handle.0.upgrade() {
+            Some(node) => node,
+            None => return false,
+        };
+        if data > node.borrow().data {
+            return false;
+        }
+        node.borrow_mut().data = data;
+        let parent = node.borrow().parent.upgrade();
+        match parent {
+            Some(parent) => if node.borrow().data < parent.borrow().data {
+                self.cut(node, &parent);
+                self.cascading_cut(parent);
+            },
+            None => {
+                let index = node.borrow().index;
+                let is_min = match self.min {
+                    Some(min) => node.borrow().data < self.roots[min].borrow().data,
+                    None => true,
+                };
+                if is_min {
+                    self.min = Some(index);
+                }
+            }
+        }
+        true
+    }
+}
@@ -1,4 +1,6 @@
 
+use crate::datastruct::heap::PriorityQueue;
+
 #[derive(Debug, Clone)]
 pub enum HeapOrder {
     MAX,
@@ -19,118 +21,32 @@ impl<T: PartialOrd> Heap<T> {
         }
     }
     pub fn push(&mut self, data: T) {
+        self.heap.push(data);
+        let last = self.heap.len() - 1;
         match &self.order {
-            HeapOrder::MAX => {
-                let mut cur = self.heap.len();
-                self.heap.push(data);
-                while cur != 0 {
-                    let parent = if cur % 2 == 0 {
-                        (cur - 1) / 2
-                    } else {
-                        cur / 2
-                    };
-                    if self.heap[parent] < self.heap[cur] {
-                        self.heap.swap(parent, cur);
-                        cur = parent;
-                    } else {
-                        break;
-                    }
-                }
-            }
-            HeapOrder::MIN => {
-                let mut cur = self.heap.len();
-                self.heap.push(data);
-                while cur != 0 {
-                    let parent = if cur % 2 == 0 {
-                        (cur - 1) / 2
-                    } else {
-                        cur / 2
-                    };
-                    if self.heap[parent] > self.heap[cur] {
-                        self.heap.swap(parent, cur);
-                        cur = parent;
-                    } else {
-                        break;
-                    }
-                }
-            }
+            HeapOrder::MAX => sift_up(&mut self.heap, last, |a, b| a > b),
+            HeapOrder::MIN => sift_up(&mut self.heap, last, |a, b| a < b),
         }
     } 
     pub fn pop(&mut self) -> Option<T> {
-        if self.heap.len() == 0 {return None;}
+        if self.heap.is_empty() {return None;}
+        let last = self.heap.len() - 1;
+        self.heap.swap(0, last);
+        let data = self.heap.pop();
         match &self.order {
-            HeapOrder::MAX => {
-                let mut cur = 0;
-                let len = self.heap.len();
-                self.heap.swap(0, len - 1);
-                let data = self.heap.pop();
-                while cur * 2 + 1 < len - 1 {
-                    let left = cur * 2 + 1;
-                    let right = cur * 2 + 2;
-                    if right < len - 1 {
-                        if self.heap[right] > self.heap[left] {
-                            if self.heap[right] > self.heap[cur] {
-                                self.heap.swap(right, cur);
-                                cur = right;
-                            } else {
-                                break;
-                            }
-                        } else {
-                             if self.heap[left] > self.heap[cur] {
-                                self.heap.swap(left, cur);
-                                cur = left;
-                            } else {
-                                break;
-                            }
-                        }
-                    } else {
-                        if self.heap[left] > self.heap[cur] {
-                            self.heap.swap(left, cur);
-                            cur = left;
-                        } else {
-                            break;
-                        }
-                    }
-                }
-                data
-            }
-            HeapOrder::MIN => {
-                let mut cur = 0;
-                let len = self.heap.len();
-                self.heap.swap(0, len - 1);
-                let data = self.heap.pop();
-                while cur * 2 + 1 < len - 1 {
-                    let left = cur * 2 + 1;
-                    let right = cur * 2 + 2;
-                    if right < len - 1 {
-                        if self.heap[right] < self.heap[left] {
-                            if self.heap[right] < self.heap[cur] {
-                                self.heap.swap(right, cur);
-                                cur = right;
-                            } else {
-                                break;
-                            }
-                        } else {
-                             if self.heap[left] < self.heap[cur] {
-                                self.heap.swap(left, cur);
-                                cur = left;
-                            } else {
-                                break;
-                            }
-                        }
-                    } else {
-                        if self.heap[left] < self.heap[cur] {
-                            self.heap.swap(left, cur);
-                            cur = left;
-                        } else {
-                            break;
-                        }
-                    }
-                }
-                data
-
-            }
+            HeapOrder::MAX => sift_down(&mut self.heap, 0, |a, b| a > b),
+            HeapOrder::MIN => sift_down(&mut self.heap, 0, |a, b| a < b),
         }
+        data
+    }
+    pub fn peek(&self) -> Option<&T> {
+        self.heap.first()
+    }
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
     }
     pub fn set_heap_order_min(&mut self) {
         if let HeapOrder::MAX = self.order {
@@ -158,3 +74,61 @@ impl<T: PartialOrd> Heap<T> {
         }
     }
 }
+
+impl<T: PartialOrd> PriorityQueue<T> for Heap<T> {
+    type Peek<'a> = &'a T where T: 'a;
+    fn push(&mut self, data: T) {
+        Heap::push(self, data);
+    }
+    fn pop(&mut self) -> Option<T> {
+        Heap::pop(self)
+    }
+    fn peek(&self) -> Option<&T> {
+        Heap::peek(self)
+    }
+    fn meld(&mut self, other: Self) {
+        for data in other.heap {
+            Heap::push(self, data);
+        }
+    }
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
+
+// before(a, b) tells whether a belongs above b
+pub(crate) fn sift_up<T>(heap: &mut [T], cur: usize, before: impl Fn(&T, &T) -> bool) {
+    sift_up_with(heap, cur, before, |heap, a, b| heap.swap(a, b));
+}
+
+pub(crate) fn sift_down<T>(heap: &mut [T], cur: usize, before: impl Fn(&T, &T) -> bool) {
+    sift_down_with(heap, cur, before, |heap, a, b| heap.swap(a, b));
+}
+
+// every move goes through swap, so a caller can keep track of where elements are
+pub(crate) fn sift_up_with<T>(heap: &mut [T], mut cur: usize, before: impl Fn(&T, &T) -> bool, mut swap: impl FnMut(&mut [T], usize, usize)) {
+    while cur != 0 {
+        let parent = (cur - 1) / 2;
+        if before(&heap[cur], &heap[parent]) {
+            swap(heap, parent, cur);
+            cur = parent;
+        } else {
+            break;
+        }
+    }
+}
+
+pub(crate) fn sift_down_with<T>(heap: &mut [T], mut cur: usize, before: impl Fn(&T, &T) -> bool, mut swap: impl FnMut(&mut [T], usize, usize)) {
+    let len = heap.len();
+    while cur * 2 + 1 < len {
+        let left = cur * 2 + 1;
+        let right = cur * 2 + 2;
+        let child = if right < len && before(&heap[right], &heap[left]) {right} else {left};
+        if before(&heap[child], &heap[cur]) {
+            swap(heap, child, cur);
+            cur = child;
+        } else {
+            break;
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::datastruct::heap::heap::{HeapOrder, sift_down_with, sift_up_with};
+
+// every element is addressed by a caller chosen handle, e.g. a vertex index
+#[derive(Debug, Clone)]
+pub struct IndexedHeap<T: PartialOrd> {
+    pub heap: Vec<usize>,
+    pub keys: Vec<Option<T>>,
+    pub position: Vec<Option<usize>>,
+    pub order: HeapOrder,
+}
+
+impl<T: PartialOrd> IndexedHeap<T> {
+    pub fn new(order: HeapOrder) -> Self {
+        Self {
+            heap: Vec::new(),
+            keys: Vec::new(),
+            position: Vec::new(),
+            order,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    pub fn contains(&self, handle: usize) -> bool {
+        matches!(self.position.get(handle), Some(Some(_)))
+    }
+    pub fn get(&self, handle: usize) -> Option<&T> {
+        match self.keys.get(handle) {
+            Some(key) => key.as_ref(),
+            None => None,
+        }
+    }
+    pub fn push(&mut self, handle: usize, key: T) -> bool {
+        if self.contains(handle) {
+            return false;
+        }
+        if handle >= self.keys.len() {
+            self.keys.resize_with(handle + 1, || None);
+            self.position.resize(handle + 1, None);
+        }
+        self.keys[handle] = Some(key);
+        self.position[handle] = Some(self.heap.len());
+        self.heap.push(handle);
+        self.sift_up(self.heap.len() - 1);
+        true
+    }
+    pub fn peek(&self) -> Option<(usize, &T)> {
+        let handle = *self.heap.first()?;
+        self.keys[handle].as_ref().map(|key| (handle, key))
+    }
+    pub fn pop(&mut self) -> Option<(usize, T)> {
+        let handle = *self.heap.first()?;
+        self.remove(handle).map(|key| (handle, key))
+    }
+    pub fn remove(&mut self, handle: usize) -> Option<T> {
+        let index = self.position.get(handle).copied().flatten()?;
+        let last = self.heap.len() - 1;
+        self.swap(index, last);
+        self.heap.pop();
+        self.position[handle] = None;
+        if index < self.heap.len() {
+            self.sift_up(index);
+            self.sift_down(index);
+        }
+        self.keys[handle].take()
+    }
+    // both fail when the handle is absent or the key would move the wrong way
+    pub fn decrease_key(&mut self, handle: usize, key: T) -> bool {
+        match self.get(handle) {
+            Some(old) if key <= *old => self.change_key(handle, key),
+            _ => false,
+        }
+    }
+    pub fn increase_key(&mut self, handle: usize, key: T) -> bool {
+        match self.get(handle) {
+            Some(old) if key >= *old => self.change_key(handle, key),
+            _ => false,
+        }
+    }
+    pub fn change_key(&mut self, handle: usize, key: T) -> bool {
+        match self.position.get(handle).copied().flatten() {
+            Some(index) => {
+                self.keys[handle] = Some(key);
+                self.sift_up(index);
+                self.sift_down(index);
+                true
+            }
+            None => false,
+        }
+    }
+    fn swap(&mut self, a: usize, b: usize) {
+        Self::swap_with(&mut self.position, &mut self.heap, a, b);
+    }
+    fn swap_with(position: &mut [Option<usize>], heap: &mut [usize], a: usize, b: usize) {
+        heap.swap(a, b);
+        position[heap[a]] = Some(a);
+        position[heap[b]] = Some(b);
+    }
+    fn sift_up(&mut self, cur: usize) {
+        let Self {heap, keys, position, order} = self;
+        sift_up_with(heap, cur, |a, b| before(keys, order, *a, *b), |heap, a, b| Self::swap_with(position, heap, a, b));
+    }
+    fn sift_down(&mut self, cur: usize) {
+        let Self {heap, keys, position, order} = self;
+        sift_down_with(heap, cur, |a, b| before(keys, order, *a, *b), |heap, a, b| Self::swap_with(position, heap, a, b));
+    }
+}
+
+// whether handle a belongs above handle b
+fn before<T: PartialOrd>(keys: &[Option<T>], order: &HeapOrder, a: usize, b: usize) -> bool {
+    match (&keys[a], &keys[b]) {
+        (Some(key_a), Some(key_b)) => match order {
+            HeapOrder::MIN => key_a < key_b,
+            HeapOrder::MAX => key_a > key_b,
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,55 @@
+use std::cmp::Ordering;
+
+use crate::datastruct::heap::heap::Heap;
+
+// ties are broken by the index of the source so equal items keep their input order
+pub struct MergeEntry<T: PartialOrd> {
+    pub data: T,
+    pub source: usize,
+}
+
+impl<T: PartialOrd> PartialEq for MergeEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data && self.source == other.source
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for MergeEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.data.partial_cmp(&other.data) {
+            Some(Ordering::Equal) => self.source.partial_cmp(&other.source),
+            ordering => ordering,
+        }
+    }
+}
+
+pub struct KWayMerge<I: Iterator> where I::Item: PartialOrd {
+    pub heap: Heap<MergeEntry<I::Item>>,
+    pub sources: Vec<I>,
+}
+
+pub fn kway_merge<I>(sources: impl IntoIterator<Item = I>) -> KWayMerge<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: PartialOrd,
+{
+    let mut sources: Vec<I::IntoIter> = sources.into_iter().map(|source| source.into_iter()).collect();
+    let mut heap = Heap::new();
+    for (source, iter) in sources.iter_mut().enumerate() {
+        if let Some(data) = iter.next() {
+            heap.push(MergeEntry {data, source});
+        }
+    }
+    KWayMerge {heap, sources}
+}
+
+impl<I: Iterator> Iterator for KWayMerge<I> where I::Item: PartialOrd {
+    type Item = I::Item;
+    fn next(&mut self) -> Option<I::Item> {
+        let MergeEntry {data, source} = self.heap.pop()?;
+        if let Some(next) = self.sources[source].next() {
+            self.heap.push(MergeEntry {data: next, source});
+        }
+        Some(data)
+    }
+}
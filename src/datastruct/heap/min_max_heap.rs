@@ -0,0 +1,151 @@
+use crate::datastruct::heap::PriorityQueue;
+
+// nodes on even depths are not greater than their descendants, nodes on odd depths not less
+#[derive(Debug, Clone)]
+pub struct MinMaxHeap<T: PartialOrd> {
+    pub heap: Vec<T>,
+}
+
+impl<T: PartialOrd> MinMaxHeap<T> {
+    pub fn new() -> Self {
+        Self {
+            heap: Vec::new(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    pub fn push(&mut self, data: T) {
+        self.heap.push(data);
+        let cur = self.heap.len() - 1;
+        if cur == 0 {
+            return;
+        }
+        let parent = (cur - 1) / 2;
+        if Self::is_min_level(cur) {
+            if self.heap[cur] > self.heap[parent] {
+                self.heap.swap(cur, parent);
+                self.bubble_up(parent, |a, b| a > b);
+            } else {
+                self.bubble_up(cur, |a, b| a < b);
+            }
+        } else if self.heap[cur] < self.heap[parent] {
+            self.heap.swap(cur, parent);
+            self.bubble_up(parent, |a, b| a < b);
+        } else {
+            self.bubble_up(cur, |a, b| a > b);
+        }
+    }
+    pub fn peek_min(&self) -> Option<&T> {
+        self.heap.first()
+    }
+    pub fn peek_max(&self) -> Option<&T> {
+        self.max_index().map(|index| &self.heap[index])
+    }
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.remove(0)
+    }
+    pub fn pop_max(&mut self) -> Option<T> {
+        let index = self.max_index()?;
+        self.remove(index)
+    }
+    fn is_min_level(index: usize) -> bool {
+        (index + 1).ilog2().is_multiple_of(2)
+    }
+    fn max_index(&self) -> Option<usize> {
+        match self.heap.len() {
+            0 => None,
+            1 => Some(0),
+            2 => Some(1),
+            _ => if self.heap[2] > self.heap[1] {Some(2)} else {Some(1)},
+        }
+    }
+    fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.heap.len() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.heap.swap(index, last);
+        let data = self.heap.pop();
+        if index < self.heap.len() {
+            if Self::is_min_level(index) {
+                self.trickle_down(index, |a, b| a < b);
+            } else {
+                self.trickle_down(index, |a, b| a > b);
+            }
+        }
+        data
+    }
+    // before is < on min levels and > on max levels
+    fn bubble_up(&mut self, mut cur: usize, before: impl Fn(&T, &T) -> bool) {
+        while cur >= 3 {
+            let grandparent = ((cur - 1) / 2 - 1) / 2;
+            if before(&self.heap[cur], &self.heap[grandparent]) {
+                self.heap.swap(cur, grandparent);
+                cur = grandparent;
+            } else {
+                break;
+            }
+        }
+    }
+    fn trickle_down(&mut self, mut cur: usize, before: impl Fn(&T, &T) -> bool) {
+        let len = self.heap.len();
+        loop {
+            let first_child = cur * 2 + 1;
+            if first_child >= len {
+                break;
+            }
+            let first_grandchild = first_child * 2 + 1;
+            let candidates = (first_child..usize::min(first_child + 2, len))
+                .chain(first_grandchild..usize::min(first_grandchild + 4, len));
+            let mut best = first_child;
+            for index in candidates {
+                if before(&self.heap[index], &self.heap[best]) {
+                    best = index;
+                }
+            }
+            if !before(&self.heap[best], &self.heap[cur]) {
+                break;
+            }
+            self.heap.swap(best, cur);
+            if best < first_grandchild {
+                break;
+            }
+            let parent = (best - 1) / 2;
+            if before(&self.heap[parent], &self.heap[best]) {
+                self.heap.swap(parent, best);
+            }
+            cur = best;
+        }
+    }
+}
+
+impl<T: PartialOrd> Default for MinMaxHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialOrd> PriorityQueue<T> for MinMaxHeap<T> {
+    type Peek<'a> = &'a T where T: 'a;
+    fn push(&mut self, data: T) {
+        MinMaxHeap::push(self, data);
+    }
+    fn pop(&mut self) -> Option<T> {
+        self.pop_min()
+    }
+    fn peek(&self) -> Option<&T> {
+        self.peek_min()
+    }
+    fn meld(&mut self, other: Self) {
+        for data in other.heap {
+            MinMaxHeap::push(self, data);
+        }
+    }
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
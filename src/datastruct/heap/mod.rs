@@ -1,4 +1,35 @@
 pub mod heap;
+pub mod comparator_heap;
+pub mod indexed_heap;
+pub mod binomial_heap;
+pub mod pairing_heap;
+pub mod fibonacci_heap;
+pub mod min_max_heap;
+pub mod dary_heap;
+pub mod top_k;
+pub mod running_median;
+pub mod kway_merge;
+
+use std::ops::Deref;
+
+pub trait PriorityQueue<T: PartialOrd> {
+    type Peek<'a>: Deref<Target = T> where Self: 'a, T: 'a;
+    fn push(&mut self, data: T);
+    fn pop(&mut self) -> Option<T>;
+    fn peek(&self) -> Option<Self::Peek<'_>>;
+    fn meld(&mut self, other: Self);
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// handles stay valid across meld and go dead once their element is popped
+pub trait DecreaseKey<T: PartialOrd>: PriorityQueue<T> {
+    type Handle;
+    fn push_with_handle(&mut self, data: T) -> Self::Handle;
+    fn decrease_key(&mut self, handle: &Self::Handle, data: T) -> bool;
+}
 
 #[cfg(test)]
 mod test_heap {
@@ -40,3 +71,369 @@ mod test_heap {
     }
 
 }
+
+#[cfg(test)]
+mod test_comparator_heap {
+    use crate::datastruct::heap::comparator_heap::ComparatorHeap;
+    use rand::{Rng, thread_rng};
+    #[derive(Debug, Clone, PartialEq)]
+    struct Task {
+        deadline: usize,
+        priority: usize,
+        name: &'static str,
+    }
+    #[test]
+    fn test_comparator() {
+        let mut heap = ComparatorHeap::new(|a: &Task, b: &Task| a.deadline.cmp(&b.deadline).then(b.priority.cmp(&a.priority)));
+        heap.push(Task {deadline: 5, priority: 1, name: "c"});
+        heap.push(Task {deadline: 3, priority: 1, name: "b"});
+        heap.push(Task {deadline: 3, priority: 9, name: "a"});
+        heap.push(Task {deadline: 8, priority: 5, name: "d"});
+        assert_eq!(heap.len(), 4);
+        assert_eq!(heap.peek().map(|t| t.name), Some("a"));
+        let names: Vec<&str> = heap.into_iter_sorted().map(|t| t.name).collect();
+        assert_eq!(names, vec!["a", "b", "c", "d"]);
+    }
+    #[test]
+    fn test_by_key() {
+        let mut heap = ComparatorHeap::by_key(|s: &String| s.len());
+        heap.push("three".to_string());
+        heap.push("a".to_string());
+        heap.push("to".to_string());
+        assert_eq!(heap.pop(), Some("a".to_string()));
+        assert_eq!(heap.pop(), Some("to".to_string()));
+        assert_eq!(heap.pop(), Some("three".to_string()));
+        assert_eq!(heap.pop(), None);
+        assert!(heap.is_empty());
+    }
+    #[test]
+    fn test_from_vec() {
+        let test: Vec<isize> = (0..10000).map(|_| thread_rng().gen()).collect();
+        let heap = ComparatorHeap::from_vec(test.clone(), |a: &isize, b: &isize| b.cmp(a));
+        let mut iterated: Vec<isize> = heap.iter().cloned().collect();
+        iterated.sort();
+        let mut standard = test;
+        standard.sort();
+        assert_eq!(iterated, standard);
+        standard.reverse();
+        assert_eq!(heap.into_sorted_vec(), standard);
+    }
+    #[test]
+    fn test_peek_mut() {
+        let mut heap = ComparatorHeap::from_vec(vec![5, 1, 8, 3], |a: &usize, b: &usize| a.cmp(b));
+        if let Some(mut top) = heap.peek_mut() {
+            *top = 10;
+        }
+        assert_eq!(heap.peek(), Some(&3));
+        assert_eq!(heap.peek_mut().map(|top| top.pop()), Some(3));
+        assert_eq!(heap.into_sorted_vec(), vec![5, 8, 10]);
+        let mut heap = ComparatorHeap::new(|a: &usize, b: &usize| a.cmp(b));
+        assert!(heap.peek_mut().is_none());
+    }
+}
+
+#[cfg(test)]
+mod test_indexed_heap {
+    use crate::datastruct::heap::heap::HeapOrder;
+    use crate::datastruct::heap::indexed_heap::IndexedHeap;
+    use rand::{Rng, thread_rng};
+    use std::collections::HashMap;
+    #[test]
+    fn test_push_pop() {
+        let mut heap = IndexedHeap::new(HeapOrder::MIN);
+        assert!(heap.push(3, 30));
+        assert!(heap.push(0, 10));
+        assert!(heap.push(7, 20));
+        assert!(!heap.push(0, 5));
+        assert_eq!(heap.len(), 3);
+        assert!(heap.contains(7));
+        assert!(!heap.contains(1));
+        assert!(!heap.contains(100));
+        assert_eq!(heap.peek(), Some((0, &10)));
+        assert_eq!(heap.pop(), Some((0, 10)));
+        assert_eq!(heap.pop(), Some((7, 20)));
+        assert_eq!(heap.pop(), Some((3, 30)));
+        assert_eq!(heap.pop(), None);
+        assert!(heap.push(0, 1));
+        assert_eq!(heap.get(0), Some(&1));
+    }
+    #[test]
+    fn test_change_key() {
+        let mut heap = IndexedHeap::new(HeapOrder::MAX);
+        for (handle, key) in [(0, 5), (1, 3), (2, 8), (3, 1)] {
+            heap.push(handle, key);
+        }
+        assert!(heap.increase_key(3, 10));
+        assert!(!heap.increase_key(3, 2));
+        assert!(heap.decrease_key(2, 0));
+        assert!(!heap.decrease_key(2, 4));
+        assert!(!heap.decrease_key(9, 4));
+        assert_eq!(heap.remove(0), Some(5));
+        assert_eq!(heap.remove(0), None);
+        assert!(!heap.contains(0));
+        assert_eq!(heap.pop(), Some((3, 10)));
+        assert_eq!(heap.pop(), Some((1, 3)));
+        assert_eq!(heap.pop(), Some((2, 0)));
+        assert!(heap.is_empty());
+    }
+    #[test]
+    fn test_random() {
+        let mut heap = IndexedHeap::new(HeapOrder::MIN);
+        let mut standard: HashMap<usize, isize> = HashMap::new();
+        for _ in 0..10000 {
+            let handle = thread_rng().gen_range(0..200);
+            let key = thread_rng().gen_range(-1000..1000);
+            match thread_rng().gen_range(0..4) {
+                0 => assert_eq!(heap.push(handle, key), standard.insert(handle, key).is_none() || {
+                    standard.insert(handle, *heap.get(handle).unwrap());
+                    false
+                }),
+                1 => assert_eq!(heap.change_key(handle, key), standard.get_mut(&handle).map(|k| *k = key).is_some()),
+                2 => assert_eq!(heap.remove(handle), standard.remove(&handle)),
+                _ => {
+                    let min = standard.iter().map(|(h, k)| (*k, *h)).min();
+                    match heap.pop() {
+                        Some((handle, key)) => {
+                            assert_eq!(Some(key), min.map(|(k, _)| k));
+                            assert_eq!(standard.remove(&handle), Some(key));
+                        }
+                        None => assert!(standard.is_empty()),
+                    }
+                }
+            }
+            assert_eq!(heap.len(), standard.len());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_priority_queue {
+    use crate::datastruct::heap::{DecreaseKey, PriorityQueue};
+    use crate::datastruct::heap::heap::Heap;
+    use crate::datastruct::heap::binomial_heap::BinomialHeap;
+    use crate::datastruct::heap::pairing_heap::PairingHeap;
+    use crate::datastruct::heap::fibonacci_heap::FibonacciHeap;
+    use crate::datastruct::heap::min_max_heap::MinMaxHeap;
+    use crate::datastruct::heap::dary_heap::DaryHeap;
+    use crate::datastruct::heap::heap::HeapOrder;
+    use rand::{Rng, thread_rng};
+    fn check_against_binary_heap<Q: PriorityQueue<isize>>(new: impl Fn() -> Q) {
+        let mut heap = new();
+        let mut standard = Heap::new();
+        for _ in 0..5000 {
+            match thread_rng().gen_range(0..5) {
+                0 | 1 => {
+                    let n = thread_rng().gen_range(-1000..1000);
+                    heap.push(n);
+                    PriorityQueue::push(&mut standard, n);
+                }
+                2 => assert_eq!(heap.pop(), PriorityQueue::pop(&mut standard)),
+                3 => {
+                    let mut other = new();
+                    let mut other_standard = Heap::new();
+                    for _ in 0..thread_rng().gen_range(0..20) {
+                        let n = thread_rng().gen_range(-1000..1000);
+                        other.push(n);
+                        PriorityQueue::push(&mut other_standard, n);
+                    }
+                    heap.meld(other);
+                    standard.meld(other_standard);
+                }
+                _ => assert_eq!(heap.peek().map(|n| *n), standard.peek().copied()),
+            }
+            assert_eq!(heap.len(), standard.len());
+        }
+        while let Some(n) = heap.pop() {
+            assert_eq!(Some(n), PriorityQueue::pop(&mut standard));
+        }
+        assert!(heap.is_empty());
+        assert!(standard.is_empty());
+    }
+    fn check_decrease_key<Q: DecreaseKey<isize>>(new: impl Fn() -> Q) {
+        let mut heap = new();
+        let mut handles = Vec::new();
+        let mut keys = Vec::new();
+        for i in 0..2000 {
+            let n = thread_rng().gen_range(0..100000);
+            handles.push(heap.push_with_handle(n));
+            keys.push(Some(n));
+            if i % 3 == 0 {
+                let n = heap.pop().unwrap();
+                let index = keys.iter().position(|k| *k == Some(n)).unwrap();
+                keys[index] = None;
+            }
+            let index = thread_rng().gen_range(0..handles.len());
+            let key = thread_rng().gen_range(-100000..100000);
+            let expected = match keys[index] {
+                Some(old) if key <= old => {
+                    keys[index] = Some(key);
+                    true
+                }
+                _ => false,
+            };
+            assert_eq!(heap.decrease_key(&handles[index], key), expected);
+            assert_eq!(heap.peek().map(|n| *n), keys.iter().flatten().min().copied());
+        }
+        let mut other = new();
+        let handle = other.push_with_handle(500000);
+        heap.meld(other);
+        assert!(heap.decrease_key(&handle, -500000));
+        assert_eq!(heap.pop(), Some(-500000));
+        assert!(!heap.decrease_key(&handle, -600000));
+        let mut standard: Vec<isize> = keys.into_iter().flatten().collect();
+        standard.sort();
+        let mut popped = Vec::new();
+        while let Some(n) = heap.pop() {
+            popped.push(n);
+        }
+        assert_eq!(popped, standard);
+    }
+    #[test]
+    fn test_binomial_heap() {
+        check_against_binary_heap(BinomialHeap::new);
+    }
+    #[test]
+    fn test_pairing_heap() {
+        check_against_binary_heap(PairingHeap::new);
+        check_decrease_key(PairingHeap::new);
+    }
+    #[test]
+    fn test_fibonacci_heap() {
+        check_against_binary_heap(FibonacciHeap::new);
+        check_decrease_key(FibonacciHeap::new);
+    }
+    #[test]
+    fn test_pairing_heap_long_chain() {
+        let mut heap = PairingHeap::new();
+        for n in (0..100000).rev() {
+            heap.push(n);
+        }
+        assert_eq!(heap.peek().map(|n| *n), Some(0));
+    }
+    #[test]
+    fn test_fibonacci_heap_long_chain() {
+        use std::cell::RefCell;
+        use std::rc::{Rc, Weak};
+        use crate::datastruct::heap::fibonacci_heap::FibonacciNode;
+        // cascading cuts can leave chains this deep, build one directly
+        let mut heap = FibonacciHeap::new();
+        let mut head: Option<Rc<RefCell<FibonacciNode<usize>>>> = None;
+        for n in (0..100000).rev() {
+            let node = Rc::new(RefCell::new(FibonacciNode {data: n, mark: false, parent: Weak::new(), index: 0, children: Vec::new()}));
+            if let Some(child) = head.take() {
+                child.borrow_mut().parent = Rc::downgrade(&node);
+                node.borrow_mut().children.push(child);
+            }
+            head = Some(node);
+        }
+        heap.roots.push(head.unwrap());
+        heap.min = Some(0);
+        heap.len = 100000;
+        assert_eq!(heap.peek().map(|n| *n), Some(0));
+    }
+    #[test]
+    fn test_min_max_heap() {
+        check_against_binary_heap(MinMaxHeap::new);
+        let mut heap = MinMaxHeap::new();
+        let mut standard = Vec::new();
+        assert_eq!(heap.peek_max(), None);
+        for _ in 0..10000 {
+            match thread_rng().gen_range(0..4) {
+                0 | 1 => {
+                    let n: isize = thread_rng().gen_range(-1000..1000);
+                    heap.push(n);
+                    standard.push(n);
+                    standard.sort();
+                }
+                2 => assert_eq!(heap.pop_min(), if standard.is_empty() {None} else {Some(standard.remove(0))}),
+                _ => assert_eq!(heap.pop_max(), standard.pop()),
+            }
+            assert_eq!(heap.peek_min(), standard.first());
+            assert_eq!(heap.peek_max(), standard.last());
+            assert_eq!(heap.len(), standard.len());
+        }
+    }
+    #[test]
+    fn test_dary_heap() {
+        for arity in 2..6 {
+            check_against_binary_heap(|| DaryHeap::new(arity));
+        }
+        let test: Vec<isize> = (0..10000).map(|_| thread_rng().gen()).collect();
+        let mut heap = DaryHeap::from_vec(test.clone(), 4, HeapOrder::MAX);
+        let mut standard = test;
+        standard.sort();
+        standard.reverse();
+        let mut popped = Vec::new();
+        while let Some(n) = heap.pop() {
+            popped.push(n);
+        }
+        assert_eq!(popped, standard);
+    }
+}
+
+#[cfg(test)]
+mod test_heap_stream {
+    use crate::datastruct::heap::top_k::TopK;
+    use crate::datastruct::heap::running_median::RunningMedian;
+    use crate::datastruct::heap::kway_merge::kway_merge;
+    use rand::{Rng, thread_rng};
+    #[test]
+    fn test_top_k() {
+        let mut largest = TopK::largest(3);
+        let mut smallest = TopK::smallest(3);
+        assert_eq!(largest.threshold(), None);
+        largest.extend(vec![5, 1, 9, 3, 7, 9]);
+        smallest.extend(vec![5, 1, 9, 3, 7, 9]);
+        assert_eq!(largest.len(), 3);
+        assert_eq!(largest.threshold(), Some(&7));
+        assert_eq!(smallest.threshold(), Some(&5));
+        assert_eq!(largest.into_sorted_vec(), vec![9, 9, 7]);
+        assert_eq!(smallest.into_sorted_vec(), vec![1, 3, 5]);
+        for _ in 0..20 {
+            let k = thread_rng().gen_range(0..50);
+            let data: Vec<isize> = (0..1000).map(|_| thread_rng().gen_range(-500..500)).collect();
+            let mut top = TopK::largest(k);
+            top.extend(data.iter().cloned());
+            let mut standard = data;
+            standard.sort();
+            standard.reverse();
+            standard.truncate(k);
+            assert_eq!(top.into_sorted_vec(), standard);
+        }
+    }
+    #[test]
+    fn test_running_median() {
+        let mut median = RunningMedian::new();
+        assert_eq!(median.median(), None);
+        assert!(median.is_empty());
+        let mut standard: Vec<i32> = Vec::new();
+        for _ in 0..2000 {
+            let n = thread_rng().gen_range(-1000..1000);
+            median.push(n);
+            standard.push(n);
+            standard.sort();
+            let len = standard.len();
+            assert_eq!(median.len(), len);
+            assert_eq!(median.low_median(), Some(&standard[(len - 1) / 2]));
+            assert_eq!(median.high_median(), Some(&standard[len / 2]));
+            assert_eq!(median.median(), Some((standard[(len - 1) / 2] as f64 + standard[len / 2] as f64) / 2.0));
+        }
+    }
+    #[test]
+    fn test_kway_merge() {
+        let merged: Vec<usize> = kway_merge(vec![vec![1, 4, 7], vec![], vec![2, 5, 8, 9], vec![3, 6]]).collect();
+        assert_eq!(merged, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let merged: Vec<char> = kway_merge(vec![vec![(1, 'a')], vec![(1, 'b')], vec![(0, 'c')]]).map(|(_, c)| c).collect();
+        assert_eq!(merged, vec!['c', 'a', 'b']);
+        let mut sources = Vec::new();
+        let mut standard = Vec::new();
+        for _ in 0..20 {
+            let mut source: Vec<isize> = (0..thread_rng().gen_range(0..100)).map(|_| thread_rng().gen_range(-1000..1000)).collect();
+            source.sort();
+            standard.extend(source.iter().cloned());
+            sources.push(source);
+        }
+        standard.sort();
+        assert_eq!(kway_merge(sources).collect::<Vec<isize>>(), standard);
+    }
+}
@@ -0,0 +1,156 @@
+use std::cell::{Ref, RefCell};
+use std::rc::{Rc, Weak};
+
+use crate::datastruct::heap::{DecreaseKey, PriorityQueue};
+
+#[derive(Debug)]
+pub struct PairingNode<T: PartialOrd> {
+    pub data: T,
+    pub parent: Weak<RefCell<PairingNode<T>>>,
+    // position in the children of the parent, so a node is cut out in O(1)
+    pub index: usize,
+    pub children: Vec<Rc<RefCell<PairingNode<T>>>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PairingHandle<T: PartialOrd>(Weak<RefCell<PairingNode<T>>>);
+
+#[derive(Debug)]
+pub struct PairingHeap<T: PartialOrd> {
+    pub root: Option<Rc<RefCell<PairingNode<T>>>>,
+    pub len: usize,
+}
+
+impl<T: PartialOrd> PairingHeap<T> {
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            len: 0,
+        }
+    }
+    fn merge(a: Rc<RefCell<PairingNode<T>>>, b: Rc<RefCell<PairingNode<T>>>) -> Rc<RefCell<PairingNode<T>>> {
+        let (parent, child) = if b.borrow().data < a.borrow().data {(b, a)} else {(a, b)};
+        let index = parent.borrow().children.len();
+        {
+            let mut child_node = child.borrow_mut();
+            child_node.parent = Rc::downgrade(&parent);
+            child_node.index = index;
+        }
+        parent.borrow_mut().children.push(child);
+        parent
+    }
+    fn merge_option(a: Option<Rc<RefCell<PairingNode<T>>>>, b: Option<Rc<RefCell<PairingNode<T>>>>) -> Option<Rc<RefCell<PairingNode<T>>>> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(Self::merge(a, b)),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+    // pair up the children left to right, then fold the pairs right to left
+    fn merge_pairs(children: Vec<Rc<RefCell<PairingNode<T>>>>) -> Option<Rc<RefCell<PairingNode<T>>>> {
+        let mut pairs = Vec::new();
+        let mut children = children.into_iter();
+        while let Some(first) = children.next() {
+            match children.next() {
+                Some(second) => pairs.push(Self::merge(first, second)),
+                None => pairs.push(first),
+            }
+        }
+        let mut root = None;
+        while let Some(tree) = pairs.pop() {
+            root = Self::merge_option(Some(tree), root);
+        }
+        root
+    }
+    fn take_data(node: Rc<RefCell<PairingNode<T>>>) -> T {
+        match Rc::try_unwrap(node) {
+            Ok(node) => node.into_inner().data,
+            Err(_) => panic!("pairing heap node is still shared"),
+        }
+    }
+}
+
+impl<T: PartialOrd> Default for PairingHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// a long chain of single children would otherwise be dropped recursively
+impl<T: PartialOrd> Drop for PairingHeap<T> {
+    fn drop(&mut self) {
+        let mut stack: Vec<Rc<RefCell<PairingNode<T>>>> = self.root.take().into_iter().collect();
+        while let Some(node) = stack.pop() {
+            stack.append(&mut node.borrow_mut().children);
+        }
+    }
+}
+
+impl<T: PartialOrd> PriorityQueue<T> for PairingHeap<T> {
+    type Peek<'a> = Ref<'a, T> where T: 'a;
+    fn push(&mut self, data: T) {
+        self.push_with_handle(data);
+    }
+    fn pop(&mut self) -> Option<T> {
+        let root = self.root.take()?;
+        let children = std::mem::take(&mut root.borrow_mut().children);
+        for child in children.iter() {
+            child.borrow_mut().parent = Weak::new();
+        }
+        self.root = Self::merge_pairs(children);
+        self.len -= 1;
+        Some(Self::take_data(root))
+    }
+    fn peek(&self) -> Option<Ref<'_, T>> {
+        self.root.as_ref().map(|root| Ref::map(root.borrow(), |node| &node.data))
+    }
+    fn meld(&mut self, mut other: Self) {
+        self.len += other.len;
+        other.len = 0;
+        self.root = Self::merge_option(self.root.take(), other.root.take());
+    }
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T: PartialOrd> DecreaseKey<T> for PairingHeap<T> {
+    type Handle = PairingHandle<T>;
+    fn push_with_handle(&mut self, data: T) -> PairingHandle<T> {
+        let node = Rc::new(RefCell::new(PairingNode {
+            data,
+            parent: Weak::new(),
+            index: 0,
+            children: Vec::new(),
+        }));
+        let handle = PairingHandle(Rc::downgrade(&node));
+        self.root = Self::merge_option(self.root.take(), Some(node));
+        self.len += 1;
+        handle
+    }
+    fn decrease_key(&mut self, handle: &PairingHandle<T>, data: T) -> bool {
+        let node = match handle.0.upgrade() {
+            Some(node) => node,
+            None => return false,
+        };
+        if data > node.borrow().data {
+            return false;
+        }
+        node.borrow_mut().data = data;
+        let parent = node.borrow().parent.upgrade();
+        if let Some(parent) = parent {
+            let index = node.borrow().index;
+            {
+                // the last child takes the place of the cut one
+                let mut parent_node = parent.borrow_mut();
+                parent_node.children.swap_remove(index);
+                if let Some(moved) = parent_node.children.get(index) {
+                    moved.borrow_mut().index = index;
+                }
+            }
+            node.borrow_mut().parent = Weak::new();
+            self.root = Self::merge_option(self.root.take(), Some(node));
+        }
+        true
+    }
+}
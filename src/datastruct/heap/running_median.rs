@@ -0,0 +1,67 @@
+use crate::datastruct::heap::heap::Heap;
+
+// lower holds the smaller half in a max heap and never has fewer elements than upper
+#[derive(Debug, Clone)]
+pub struct RunningMedian<T: PartialOrd> {
+    pub lower: Heap<T>,
+    pub upper: Heap<T>,
+}
+
+impl<T: PartialOrd> RunningMedian<T> {
+    pub fn new() -> Self {
+        let mut lower = Heap::new();
+        lower.set_heap_order_max();
+        Self {
+            lower,
+            upper: Heap::new(),
+        }
+    }
+    pub fn push(&mut self, data: T) {
+        let to_lower = match self.lower.peek() {
+            Some(max) => data <= *max,
+            None => true,
+        };
+        if to_lower {
+            self.lower.push(data);
+        } else {
+            self.upper.push(data);
+        }
+        if self.lower.len() > self.upper.len() + 1 {
+            if let Some(data) = self.lower.pop() {
+                self.upper.push(data);
+            }
+        } else if self.upper.len() > self.lower.len() {
+            if let Some(data) = self.upper.pop() {
+                self.lower.push(data);
+            }
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+    pub fn low_median(&self) -> Option<&T> {
+        self.lower.peek()
+    }
+    pub fn high_median(&self) -> Option<&T> {
+        if self.lower.len() > self.upper.len() {
+            self.lower.peek()
+        } else {
+            self.upper.peek()
+        }
+    }
+    pub fn median(&self) -> Option<f64> where T: Copy + Into<f64> {
+        match (self.low_median(), self.high_median()) {
+            (Some(&low), Some(&high)) => Some((low.into() + high.into()) / 2.0),
+            _ => None,
+        }
+    }
+}
+
+impl<T: PartialOrd> Default for RunningMedian<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,69 @@
+use crate::datastruct::heap::heap::Heap;
+
+// the heap is ordered the other way round, so its top is the first element to evict
+#[derive(Debug, Clone)]
+pub struct TopK<T: PartialOrd> {
+    pub heap: Heap<T>,
+    pub k: usize,
+    pub largest: bool,
+}
+
+impl<T: PartialOrd> TopK<T> {
+    pub fn largest(k: usize) -> Self {
+        Self {
+            heap: Heap::new(),
+            k,
+            largest: true,
+        }
+    }
+    pub fn smallest(k: usize) -> Self {
+        let mut heap = Heap::new();
+        heap.set_heap_order_max();
+        Self {
+            heap,
+            k,
+            largest: false,
+        }
+    }
+    pub fn push(&mut self, data: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(data);
+            return;
+        }
+        let replace = match self.heap.peek() {
+            Some(threshold) => if self.largest {data > *threshold} else {data < *threshold},
+            None => false,
+        };
+        if replace {
+            self.heap.pop();
+            self.heap.push(data);
+        }
+    }
+    // the weakest element that is still kept
+    pub fn threshold(&self) -> Option<&T> {
+        self.heap.peek()
+    }
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    // best first
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut result = Vec::with_capacity(self.heap.len());
+        while let Some(data) = self.heap.pop() {
+            result.push(data);
+        }
+        result.reverse();
+        result
+    }
+}
+
+impl<T: PartialOrd> Extend<T> for TopK<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for data in iter {
+            self.push(data);
+        }
+    }
+}
@@ -80,6 +80,17 @@ impl Number {
         self.bin = bin;
         self.bits = usize::max(self.bits, bits);
     }
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+    // the fewest bits that hold the value, sign bit included
+    pub fn significant_bits(&self) -> usize {
+        let bin = self.bin.as_bytes();
+        match bin.iter().position(|&b| b != bin[0]) {
+            Some(index) => self.bits - index + 1,
+            None => 1,
+        }
+    }
     pub fn test(&self) -> bool {
         if let Some(&n) = self.bin.as_bytes().last() {
             n == '1' as u8 
@@ -327,6 +338,7 @@ impl Number {
         for i in (0..longer.len() - shorter.len()).rev() {
             let val = longer[i] + carry - '0' as u8;
             dec.insert(0, ((val % 10) + '0' as u8) as char);
+            carry = val / 10;
         }
         if carry != 0 {
             dec.insert(0, (carry + '0' as u8) as char);
@@ -659,10 +671,26 @@ impl std::ops::MulAssign for Number {
             lhs <<= Self::one(lhs.bits);
             rhs.ushr_number();
         }
-        std::mem::swap(self, &mut lhs); 
+        std::mem::swap(self, &mut answer); 
     }
 }
 
 #[cfg(test)]
 mod test_number {
+    use crate::datastruct::number::Number;
+    #[test]
+    fn test_dec() {
+        for dec in ["0", "7", "1234567", "-1234567", "123456789012345678901234567890"] {
+            assert_eq!(Number::from_dec(dec, 128).unwrap().dec(), dec);
+        }
+    }
+    #[test]
+    fn test_mul() {
+        let a = Number::from_dec("123456789", 128).unwrap();
+        let b = Number::from_dec("-987654321", 128).unwrap();
+        assert_eq!((a.clone() * b.clone()).dec(), "-121932631112635269");
+        let mut c = a;
+        c *= b;
+        assert_eq!(c.dec(), "-121932631112635269");
+    }
 }
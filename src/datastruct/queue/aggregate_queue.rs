@@ -0,0 +1,71 @@
+use crate::datastruct::stack::aggregate_stack::{AggregateStack, Monoid};
+
+// front is the top of the out stack, back is the top of the in stack
+#[derive(Clone)]
+pub struct AggregateQueue<T: PartialEq, M: Monoid<T> + Clone> {
+    pub input: AggregateStack<T, M>,
+    pub output: AggregateStack<T, M>,
+}
+
+impl<T: PartialEq, M: Monoid<T> + Clone> AggregateQueue<T, M> {
+    pub fn new(monoid: M) -> Self {
+        let mut output = AggregateStack::new(monoid.clone());
+        output.reversed = true;
+        Self {
+            input: AggregateStack::new(monoid),
+            output,
+        }
+    }
+    pub fn push(&mut self, data: T) {
+        self.input.push(data);
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        if self.output.is_empty() {
+            while let Some(data) = self.input.pop() {
+                self.output.push(data);
+            }
+        }
+        self.output.pop()
+    }
+    pub fn aggregate(&self) -> M::Value {
+        self.input.monoid.combine(&self.output.aggregate(), &self.input.aggregate())
+    }
+    pub fn len(&self) -> usize {
+        self.input.len() + self.output.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.input.is_empty() && self.output.is_empty()
+    }
+}
+
+// derive would not require the aggregated values to be Debug, which the stacks need
+impl<T: PartialEq + std::fmt::Debug, M: Monoid<T> + Clone + std::fmt::Debug> std::fmt::Debug for AggregateQueue<T, M>
+where
+    M::Value: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AggregateQueue").field("input", &self.input).field("output", &self.output).finish()
+    }
+}
+
+pub fn sliding_window_aggregate<T, M>(data: impl IntoIterator<Item = T>, window: usize, monoid: M) -> Vec<M::Value>
+where
+    T: PartialEq,
+    M: Monoid<T> + Clone,
+{
+    let mut result = Vec::new();
+    if window == 0 {
+        return result;
+    }
+    let mut queue = AggregateQueue::new(monoid);
+    for n in data {
+        queue.push(n);
+        if queue.len() > window {
+            queue.pop();
+        }
+        if queue.len() == window {
+            result.push(queue.aggregate());
+        }
+    }
+    result
+}
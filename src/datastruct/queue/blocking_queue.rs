@@ -0,0 +1,133 @@
+use std::collections::VecDeque;
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq)]
+pub enum BlockingQueueError<T> {
+    Full(T),
+    Closed(T),
+}
+
+#[derive(Debug)]
+struct BlockingQueueState<T> {
+    queue: VecDeque<T>,
+    closed: bool,
+}
+
+#[derive(Debug)]
+pub struct BlockingQueue<T> {
+    capacity: usize,
+    state: Mutex<BlockingQueueState<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+}
+
+impl<T> BlockingQueue<T> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "blocking queue capacity must be positive");
+        Self {
+            capacity,
+            state: Mutex::new(BlockingQueueState {
+                queue: VecDeque::with_capacity(capacity),
+                closed: false,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    pub fn len(&self) -> usize {
+        self.lock().queue.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.lock().queue.is_empty()
+    }
+    pub fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+    pub fn push(&self, data: T) -> Result<(), BlockingQueueError<T>> {
+        let mut state = self.lock();
+        while !state.closed && state.queue.len() == self.capacity {
+            state = self.not_full.wait(state).expect("blocking queue lock poisoned");
+        }
+        if state.closed {
+            return Err(BlockingQueueError::Closed(data));
+        }
+        state.queue.push_back(data);
+        self.not_empty.notify_one();
+        Ok(())
+    }
+    pub fn try_push(&self, data: T) -> Result<(), BlockingQueueError<T>> {
+        let mut state = self.lock();
+        if state.closed {
+            return Err(BlockingQueueError::Closed(data));
+        }
+        if state.queue.len() == self.capacity {
+            return Err(BlockingQueueError::Full(data));
+        }
+        state.queue.push_back(data);
+        self.not_empty.notify_one();
+        Ok(())
+    }
+    pub fn pop(&self) -> Option<T> {
+        let mut state = self.lock();
+        loop {
+            if let Some(data) = state.queue.pop_front() {
+                self.not_full.notify_one();
+                return Some(data);
+            }
+            if state.closed {
+                return None;
+            }
+            state = self.not_empty.wait(state).expect("blocking queue lock poisoned");
+        }
+    }
+    pub fn try_pop(&self) -> Option<T> {
+        let mut state = self.lock();
+        let data = state.queue.pop_front();
+        if data.is_some() {
+            self.not_full.notify_one();
+        }
+        data
+    }
+    pub fn pop_timeout(&self, timeout: Duration) -> Option<T> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.lock();
+        loop {
+            if let Some(data) = state.queue.pop_front() {
+                self.not_full.notify_one();
+                return Some(data);
+            }
+            if state.closed {
+                return None;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return None;
+            }
+            state = self.not_empty
+                .wait_timeout(state, deadline - now)
+                .expect("blocking queue lock poisoned")
+                .0;
+        }
+    }
+    pub fn close(&self) {
+        let mut state = self.lock();
+        state.closed = true;
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+    pub fn drain(&self) -> Vec<T> {
+        let mut state = self.lock();
+        let data: Vec<T> = state.queue.drain(..).collect();
+        if !data.is_empty() {
+            self.not_full.notify_all();
+        }
+        data
+    }
+    fn lock(&self) -> MutexGuard<'_, BlockingQueueState<T>> {
+        self.state.lock().expect("blocking queue lock poisoned")
+    }
+}
@@ -1 +1,390 @@
 pub mod queue;
+pub mod blocking_queue;
+pub mod spsc_queue;
+pub mod mpmc_queue;
+pub mod monotonic_deque;
+pub mod aggregate_queue;
+
+#[cfg(test)]
+mod test_blocking_queue {
+    use crate::datastruct::queue::blocking_queue::{BlockingQueue, BlockingQueueError};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+    #[test]
+    fn test_push_pop() {
+        let queue = BlockingQueue::new(3);
+        assert_eq!(queue.push(1), Ok(()));
+        assert_eq!(queue.push(2), Ok(()));
+        assert_eq!(queue.try_push(3), Ok(()));
+        assert_eq!(queue.try_push(4), Err(BlockingQueueError::Full(4)));
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.try_pop(), Some(2));
+        assert_eq!(queue.pop(), Some(3));
+        assert_eq!(queue.try_pop(), None);
+        assert!(queue.is_empty());
+    }
+    #[test]
+    fn test_pop_timeout() {
+        let queue = BlockingQueue::<usize>::new(1);
+        let start = Instant::now();
+        assert_eq!(queue.pop_timeout(Duration::from_millis(50)), None);
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        let queue = Arc::new(queue);
+        let producer = {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(20));
+                queue.push(7).unwrap();
+            })
+        };
+        assert_eq!(queue.pop_timeout(Duration::from_secs(10)), Some(7));
+        producer.join().unwrap();
+    }
+    #[test]
+    fn test_close_and_drain() {
+        let queue = BlockingQueue::new(4);
+        queue.push(1).unwrap();
+        queue.push(2).unwrap();
+        queue.push(3).unwrap();
+        queue.close();
+        assert!(queue.is_closed());
+        assert_eq!(queue.push(4), Err(BlockingQueueError::Closed(4)));
+        assert_eq!(queue.try_push(4), Err(BlockingQueueError::Closed(4)));
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.drain(), vec![2, 3]);
+        assert_eq!(queue.pop(), None);
+        assert_eq!(queue.pop_timeout(Duration::from_secs(10)), None);
+    }
+    #[test]
+    fn test_close_wakes_blocked_threads() {
+        let queue = Arc::new(BlockingQueue::<usize>::new(1));
+        let consumers: Vec<_> = (0..4).map(|_| {
+            let queue = queue.clone();
+            thread::spawn(move || queue.pop())
+        }).collect();
+        queue.push(0).unwrap();
+        thread::sleep(Duration::from_millis(20));
+        queue.close();
+        let mut popped: Vec<usize> = consumers.into_iter().filter_map(|c| c.join().unwrap()).collect();
+        popped.extend(queue.drain());
+        assert_eq!(popped, vec![0]);
+        let queue = Arc::new(BlockingQueue::new(1));
+        queue.push(0).unwrap();
+        let producer = {
+            let queue = queue.clone();
+            thread::spawn(move || queue.push(1))
+        };
+        thread::sleep(Duration::from_millis(20));
+        queue.close();
+        assert_eq!(producer.join().unwrap(), Err(BlockingQueueError::Closed(1)));
+    }
+    #[test]
+    fn test_multiple_producers_consumers() {
+        const PRODUCERS: usize = 8;
+        const CONSUMERS: usize = 8;
+        const COUNT: usize = 10000;
+        let queue = Arc::new(BlockingQueue::new(16));
+        let producers: Vec<_> = (0..PRODUCERS).map(|p| {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                for i in 0..COUNT {
+                    queue.push(p * COUNT + i).unwrap();
+                }
+            })
+        }).collect();
+        let consumers: Vec<_> = (0..CONSUMERS).map(|_| {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                let mut popped = Vec::new();
+                while let Some(n) = queue.pop() {
+                    popped.push(n);
+                }
+                popped
+            })
+        }).collect();
+        for producer in producers {
+            producer.join().unwrap();
+        }
+        queue.close();
+        let mut popped = Vec::new();
+        for consumer in consumers {
+            let part = consumer.join().unwrap();
+            for w in part.windows(2) {
+                if w[0] / COUNT == w[1] / COUNT {
+                    assert!(w[0] < w[1]);
+                }
+            }
+            popped.extend(part);
+        }
+        popped.sort();
+        assert_eq!(popped, (0..PRODUCERS * COUNT).collect::<Vec<_>>());
+    }
+}
+
+#[cfg(test)]
+mod test_spsc_queue {
+    use crate::datastruct::queue::spsc_queue::SpscQueue;
+    use rand::{Rng, thread_rng};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    #[test]
+    fn test_push_pop() {
+        let mut queue = SpscQueue::new(3);
+        assert_eq!(queue.capacity(), 3);
+        assert_eq!(queue.push(1), Ok(()));
+        assert_eq!(queue.push(2), Ok(()));
+        assert_eq!(queue.push(3), Ok(()));
+        assert_eq!(queue.push(4), Err(4));
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.push(4), Ok(()));
+        assert_eq!(queue.pop(), Some(2));
+        assert_eq!(queue.pop(), Some(3));
+        assert_eq!(queue.pop(), Some(4));
+        assert_eq!(queue.pop(), None);
+        assert!(queue.is_empty());
+    }
+    #[test]
+    fn test_drop_remaining() {
+        struct Counted(Arc<AtomicUsize>);
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        let dropped = Arc::new(AtomicUsize::new(0));
+        let mut queue = SpscQueue::new(8);
+        for _ in 0..5 {
+            assert!(queue.push(Counted(dropped.clone())).is_ok());
+        }
+        drop(queue.pop());
+        drop(queue);
+        assert_eq!(dropped.load(Ordering::SeqCst), 5);
+    }
+    #[test]
+    fn test_threaded_random() {
+        for _ in 0..20 {
+            let capacity = thread_rng().gen_range(1..64);
+            let count: usize = thread_rng().gen_range(1000..20000);
+            let (mut producer, mut consumer) = SpscQueue::new(capacity).split();
+            let handle = thread::spawn(move || {
+                for i in 0..count {
+                    let mut data = i;
+                    while let Err(back) = producer.push(data) {
+                        data = back;
+                        thread::yield_now();
+                    }
+                    if thread_rng().gen_ratio(1, 100) {
+                        thread::yield_now();
+                    }
+                }
+            });
+            let mut expected = 0;
+            while expected < count {
+                match consumer.pop() {
+                    Some(n) => {
+                        assert_eq!(n, expected);
+                        expected += 1;
+                    }
+                    None => thread::yield_now(),
+                }
+            }
+            handle.join().unwrap();
+            assert_eq!(consumer.pop(), None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_mpmc_queue {
+    use crate::datastruct::queue::mpmc_queue::MpmcQueue;
+    use rand::{Rng, thread_rng};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    #[test]
+    fn test_push_pop() {
+        let queue = MpmcQueue::new(3);
+        assert_eq!(queue.capacity(), 3);
+        for i in 0..3 {
+            assert_eq!(queue.push(i), Ok(()));
+        }
+        assert_eq!(queue.push(3), Err(3));
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.pop(), Some(0));
+        assert_eq!(queue.push(3), Ok(()));
+        assert_eq!(queue.push(4), Err(4));
+        for i in 1..4 {
+            assert_eq!(queue.pop(), Some(i));
+        }
+        assert_eq!(queue.pop(), None);
+        assert!(queue.is_empty());
+        // keep wrapping around the four slots while only three are ever used
+        for i in 0..20 {
+            assert_eq!(queue.push(i), Ok(()));
+            assert_eq!(queue.push(i + 100), Ok(()));
+            assert_eq!(queue.push(i + 200), Ok(()));
+            assert_eq!(queue.push(i + 300), Err(i + 300));
+            assert_eq!(queue.pop(), Some(i));
+            assert_eq!(queue.pop(), Some(i + 100));
+            assert_eq!(queue.pop(), Some(i + 200));
+        }
+        let queue = MpmcQueue::new(1);
+        assert_eq!(queue.capacity(), 1);
+        assert_eq!(queue.push(1), Ok(()));
+        assert_eq!(queue.push(2), Err(2));
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.push(2), Ok(()));
+        assert_eq!(queue.pop(), Some(2));
+        assert_eq!(queue.pop(), None);
+        let queue = MpmcQueue::new(4);
+        assert_eq!(queue.capacity(), 4);
+        for i in 0..4 {
+            assert_eq!(queue.push(i), Ok(()));
+        }
+        assert_eq!(queue.push(4), Err(4));
+    }
+    #[test]
+    fn test_threaded_random() {
+        for _ in 0..10 {
+            let capacity = thread_rng().gen_range(1..64);
+            let producers = thread_rng().gen_range(1..6);
+            let consumers = thread_rng().gen_range(1..6);
+            let count: usize = thread_rng().gen_range(1000..10000);
+            let queue = Arc::new(MpmcQueue::new(capacity));
+            let popped_count = Arc::new(AtomicUsize::new(0));
+            let producer_handles: Vec<_> = (0..producers).map(|p| {
+                let queue = queue.clone();
+                thread::spawn(move || {
+                    for i in 0..count {
+                        let mut data = p * count + i;
+                        while let Err(back) = queue.push(data) {
+                            data = back;
+                            thread::yield_now();
+                        }
+                        if thread_rng().gen_ratio(1, 100) {
+                            thread::yield_now();
+                        }
+                    }
+                })
+            }).collect();
+            let consumer_handles: Vec<_> = (0..consumers).map(|_| {
+                let queue = queue.clone();
+                let popped_count = popped_count.clone();
+                thread::spawn(move || {
+                    let mut popped = Vec::new();
+                    while popped_count.load(Ordering::SeqCst) < producers * count {
+                        match queue.pop() {
+                            Some(n) => {
+                                popped_count.fetch_add(1, Ordering::SeqCst);
+                                popped.push(n);
+                            }
+                            None => thread::yield_now(),
+                        }
+                    }
+                    popped
+                })
+            }).collect();
+            for handle in producer_handles {
+                handle.join().unwrap();
+            }
+            let mut popped = Vec::new();
+            for handle in consumer_handles {
+                let part = handle.join().unwrap();
+                for w in part.windows(2) {
+                    if w[0] / count == w[1] / count {
+                        assert!(w[0] < w[1]);
+                    }
+                }
+                popped.extend(part);
+            }
+            popped.sort();
+            assert_eq!(popped, (0..producers * count).collect::<Vec<_>>());
+            assert_eq!(queue.pop(), None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_monotonic_deque {
+    use crate::datastruct::queue::monotonic_deque::*;
+    use crate::datastruct::stack::monotonic_stack::MonotonicOrder;
+    use rand::{Rng, thread_rng};
+    #[test]
+    fn test_push_pop() {
+        let mut deque = MonotonicDeque::new(MonotonicOrder::DECREASING);
+        deque.push(1);
+        deque.push(3);
+        deque.push(2);
+        assert_eq!(deque.front(), Some(&3));
+        assert_eq!(deque.len(), 3);
+        // 1 was evicted by the push of 3, which is not the same as an empty window
+        assert_eq!(deque.pop(), Some(MonotonicPop::Evicted));
+        assert_eq!(deque.pop(), Some(MonotonicPop::Kept(3)));
+        assert_eq!(deque.front(), Some(&2));
+        assert_eq!(deque.pop(), Some(MonotonicPop::Kept(2)));
+        assert!(deque.is_empty());
+        assert_eq!(deque.pop(), None);
+        assert_eq!(deque.front(), None);
+        deque.push(5);
+        assert_eq!(deque.pop(), Some(MonotonicPop::Kept(5)));
+        assert_eq!(deque.pop(), None);
+    }
+    #[test]
+    fn test_sliding_window() {
+        let data = [1, 3, -1, -3, 5, 3, 6, 7];
+        assert_eq!(sliding_window_max(&data, 3), vec![3, 3, 5, 5, 6, 7]);
+        assert_eq!(sliding_window_min(&data, 3), vec![-1, -3, -3, -3, 3, 3]);
+        assert_eq!(sliding_window_max(&data, 0), vec![]);
+        assert_eq!(sliding_window_max(&data, 9), vec![]);
+        for _ in 0..100 {
+            let data: Vec<isize> = (0..200).map(|_| thread_rng().gen_range(0..20)).collect();
+            let window = thread_rng().gen_range(1..20);
+            let max: Vec<isize> = data.windows(window).map(|w| *w.iter().max().unwrap()).collect();
+            let min: Vec<isize> = data.windows(window).map(|w| *w.iter().min().unwrap()).collect();
+            assert_eq!(sliding_window_max(&data, window), max);
+            assert_eq!(sliding_window_min(&data, window), min);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_aggregate_queue {
+    use crate::datastruct::queue::aggregate_queue::*;
+    use crate::datastruct::stack::aggregate_stack::{FnMonoid, MaxMonoid, MinMonoid};
+    use rand::{Rng, thread_rng};
+    use std::collections::VecDeque;
+    #[test]
+    fn test_push_pop() {
+        let mut queue = AggregateQueue::new(FnMonoid {identity: String::new(), combine: |a: &String, b: &String| format!("{}{}", a, b)});
+        let mut standard = VecDeque::new();
+        for _ in 0..2000 {
+            if thread_rng().gen_ratio(3, 5) {
+                let c = thread_rng().gen_range(b'a'..=b'z') as char;
+                queue.push(c.to_string());
+                standard.push_back(c.to_string());
+            } else {
+                assert_eq!(queue.pop(), standard.pop_front());
+            }
+            assert_eq!(queue.aggregate(), standard.iter().cloned().collect::<String>());
+            assert_eq!(queue.len(), standard.len());
+        }
+        while queue.pop().is_some() {}
+        assert!(queue.is_empty());
+        assert_eq!(queue.aggregate(), "");
+        let mut queue = AggregateQueue::new(MaxMonoid::new());
+        queue.push(1);
+        assert!(format!("{:?}", queue).starts_with("AggregateQueue { input: AggregateStack"));
+    }
+    #[test]
+    fn test_sliding_window_aggregate() {
+        let data = vec![1, 3, -1, -3, 5, 3, 6, 7];
+        assert_eq!(sliding_window_aggregate(data.clone(), 3, MaxMonoid::new()), vec![Some(3), Some(3), Some(5), Some(5), Some(6), Some(7)]);
+        assert_eq!(sliding_window_aggregate(data.clone(), 3, MinMonoid::new()), vec![Some(-1), Some(-3), Some(-3), Some(-3), Some(3), Some(3)]);
+        assert_eq!(sliding_window_aggregate(data.clone(), 3, FnMonoid {identity: 0, combine: |a: &isize, b: &isize| a + b}), vec![3, -1, 1, 5, 14, 16]);
+        assert_eq!(sliding_window_aggregate(data, 0, MinMonoid::new()), vec![]);
+    }
+}
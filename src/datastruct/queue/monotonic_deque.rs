@@ -0,0 +1,95 @@
+use std::collections::VecDeque;
+
+use crate::datastruct::stack::monotonic_stack::MonotonicOrder;
+
+// what pop found at the old end of the window
+#[derive(Debug, Clone, PartialEq)]
+pub enum MonotonicPop<T> {
+    // the element was still kept and is returned
+    Kept(T),
+    // a later push had already evicted the element
+    Evicted,
+}
+
+#[derive(Debug, Clone)]
+pub struct MonotonicDeque<T: PartialOrd> {
+    pub deque: VecDeque<(usize, T)>,
+    pub order: MonotonicOrder,
+    pub pushed: usize,
+    pub popped: usize,
+}
+
+impl<T: PartialOrd> MonotonicDeque<T> {
+    pub fn new(order: MonotonicOrder) -> Self {
+        Self {
+            deque: VecDeque::new(),
+            order,
+            pushed: 0,
+            popped: 0,
+        }
+    }
+    pub fn push(&mut self, data: T) {
+        while let Some((_, back)) = self.deque.back() {
+            let violate = match self.order {
+                MonotonicOrder::INCREASING => *back > data,
+                MonotonicOrder::DECREASING => *back < data,
+            };
+            if !violate {
+                break;
+            }
+            self.deque.pop_back();
+        }
+        self.deque.push_back((self.pushed, data));
+        self.pushed += 1;
+    }
+    // removes the oldest element of the window, None only when the window is empty
+    pub fn pop(&mut self) -> Option<MonotonicPop<T>> {
+        if self.popped == self.pushed {
+            return None;
+        }
+        let oldest = self.popped;
+        self.popped += 1;
+        match self.deque.front() {
+            Some((index, _)) if *index == oldest => self.deque.pop_front().map(|(_, data)| MonotonicPop::Kept(data)),
+            _ => Some(MonotonicPop::Evicted),
+        }
+    }
+    // the minimum of the window for INCREASING, the maximum for DECREASING
+    pub fn front(&self) -> Option<&T> {
+        self.deque.front().map(|(_, data)| data)
+    }
+    pub fn len(&self) -> usize {
+        self.pushed - self.popped
+    }
+    pub fn is_empty(&self) -> bool {
+        self.pushed == self.popped
+    }
+}
+
+fn sliding_window_by<T: PartialOrd + Clone>(data: &[T], window: usize, order: MonotonicOrder) -> Vec<T> {
+    let mut result = Vec::new();
+    if window == 0 {
+        return result;
+    }
+    let mut deque = MonotonicDeque::new(order);
+    for n in data.iter() {
+        deque.push(n.clone());
+        if deque.len() > window {
+            deque.pop();
+        }
+        if deque.len() == window {
+            if let Some(front) = deque.front() {
+                result.push(front.clone());
+            }
+        }
+    }
+    result
+}
+
+pub fn sliding_window_max<T: PartialOrd + Clone>(data: &[T], window: usize) -> Vec<T> {
+    sliding_window_by(data, window, MonotonicOrder::DECREASING)
+}
+
+pub fn sliding_window_min<T: PartialOrd + Clone>(data: &[T], window: usize) -> Vec<T> {
+    sliding_window_by(data, window, MonotonicOrder::INCREASING)
+}
@@ -0,0 +1,109 @@
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct Slot<T> {
+    sequence: AtomicUsize,
+    data: UnsafeCell<MaybeUninit<T>>,
+}
+
+// bounded queue after Dmitry Vyukov, the slots are rounded up to a power of two
+// and are at least 2, with a single slot a full mark would read as the next empty one
+// push still stops at exactly the requested capacity, the spare slots are never filled
+pub struct MpmcQueue<T> {
+    slots: Box<[Slot<T>]>,
+    mask: usize,
+    capacity: usize,
+    enqueue_pos: AtomicUsize,
+    dequeue_pos: AtomicUsize,
+}
+
+unsafe impl<T: Send> Send for MpmcQueue<T> {}
+unsafe impl<T: Send> Sync for MpmcQueue<T> {}
+
+impl<T> MpmcQueue<T> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "mpmc queue capacity must be positive");
+        let slots = capacity.next_power_of_two().max(2);
+        Self {
+            slots: (0..slots).map(|i| Slot {
+                sequence: AtomicUsize::new(i),
+                data: UnsafeCell::new(MaybeUninit::uninit()),
+            }).collect(),
+            mask: slots - 1,
+            capacity,
+            enqueue_pos: AtomicUsize::new(0),
+            dequeue_pos: AtomicUsize::new(0),
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    pub fn push(&self, data: T) -> Result<(), T> {
+        let mut pos = self.enqueue_pos.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.slots[pos & self.mask];
+            let sequence = slot.sequence.load(Ordering::Acquire);
+            let diff = sequence.wrapping_sub(pos) as isize;
+            // dequeue_pos only grows, so a stale read can refuse a push but never overfill
+            let dequeue = self.dequeue_pos.load(Ordering::Acquire);
+            if diff == 0 && pos.wrapping_sub(dequeue) as isize >= self.capacity as isize {
+                return Err(data);
+            }
+            if diff == 0 {
+                match self.enqueue_pos.compare_exchange_weak(pos, pos.wrapping_add(1), Ordering::Relaxed, Ordering::Relaxed) {
+                    Ok(_) => {
+                        unsafe { (*slot.data.get()).write(data); }
+                        slot.sequence.store(pos.wrapping_add(1), Ordering::Release);
+                        return Ok(());
+                    }
+                    Err(current) => pos = current,
+                }
+            } else if diff < 0 {
+                return Err(data);
+            } else {
+                pos = self.enqueue_pos.load(Ordering::Relaxed);
+            }
+        }
+    }
+    pub fn pop(&self) -> Option<T> {
+        let mut pos = self.dequeue_pos.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.slots[pos & self.mask];
+            let sequence = slot.sequence.load(Ordering::Acquire);
+            let diff = sequence.wrapping_sub(pos.wrapping_add(1)) as isize;
+            if diff == 0 {
+                match self.dequeue_pos.compare_exchange_weak(pos, pos.wrapping_add(1), Ordering::Relaxed, Ordering::Relaxed) {
+                    Ok(_) => {
+                        let data = unsafe { (*slot.data.get()).assume_init_read() };
+                        slot.sequence.store(pos.wrapping_add(self.mask + 1), Ordering::Release);
+                        return Some(data);
+                    }
+                    Err(current) => pos = current,
+                }
+            } else if diff < 0 {
+                return None;
+            } else {
+                pos = self.dequeue_pos.load(Ordering::Relaxed);
+            }
+        }
+    }
+    pub fn len(&self) -> usize {
+        loop {
+            let dequeue = self.dequeue_pos.load(Ordering::Acquire);
+            let enqueue = self.enqueue_pos.load(Ordering::Acquire);
+            if self.dequeue_pos.load(Ordering::Acquire) == dequeue {
+                return enqueue.wrapping_sub(dequeue).min(self.capacity());
+            }
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Drop for MpmcQueue<T> {
+    fn drop(&mut self) {
+        while self.pop().is_some() {}
+    }
+}
@@ -0,0 +1,127 @@
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ring buffer with one spare slot so that head == tail always means empty
+struct SpscBuffer<T> {
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+unsafe impl<T: Send> Send for SpscBuffer<T> {}
+unsafe impl<T: Send> Sync for SpscBuffer<T> {}
+
+impl<T> SpscBuffer<T> {
+    fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "spsc queue capacity must be positive");
+        Self {
+            slots: (0..capacity + 1).map(|_| UnsafeCell::new(MaybeUninit::uninit())).collect(),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+    fn next(&self, index: usize) -> usize {
+        if index + 1 == self.slots.len() {0} else {index + 1}
+    }
+    // safety: only one thread may call push at a time
+    unsafe fn push(&self, data: T) -> Result<(), T> {
+        let tail = self.tail.load(Ordering::Relaxed);
+        let next = self.next(tail);
+        if next == self.head.load(Ordering::Acquire) {
+            return Err(data);
+        }
+        (*self.slots[tail].get()).write(data);
+        self.tail.store(next, Ordering::Release);
+        Ok(())
+    }
+    // safety: only one thread may call pop at a time
+    unsafe fn pop(&self) -> Option<T> {
+        let head = self.head.load(Ordering::Relaxed);
+        if head == self.tail.load(Ordering::Acquire) {
+            return None;
+        }
+        let data = (*self.slots[head].get()).assume_init_read();
+        self.head.store(self.next(head), Ordering::Release);
+        Some(data)
+    }
+    fn len(&self) -> usize {
+        let head = self.head.load(Ordering::Acquire);
+        let tail = self.tail.load(Ordering::Acquire);
+        if tail >= head {tail - head} else {tail + self.slots.len() - head}
+    }
+}
+
+impl<T> Drop for SpscBuffer<T> {
+    fn drop(&mut self) {
+        let tail = *self.tail.get_mut();
+        let mut head = *self.head.get_mut();
+        while head != tail {
+            unsafe { self.slots[head].get_mut().assume_init_drop(); }
+            head = self.next(head);
+        }
+    }
+}
+
+pub struct SpscQueue<T> {
+    buffer: Arc<SpscBuffer<T>>,
+}
+
+pub struct SpscProducer<T> {
+    buffer: Arc<SpscBuffer<T>>,
+}
+
+pub struct SpscConsumer<T> {
+    buffer: Arc<SpscBuffer<T>>,
+}
+
+impl<T> SpscQueue<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            buffer: Arc::new(SpscBuffer::new(capacity)),
+        }
+    }
+    pub fn push(&mut self, data: T) -> Result<(), T> {
+        unsafe { self.buffer.push(data) }
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        unsafe { self.buffer.pop() }
+    }
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn capacity(&self) -> usize {
+        self.buffer.slots.len() - 1
+    }
+    pub fn split(self) -> (SpscProducer<T>, SpscConsumer<T>) {
+        (SpscProducer { buffer: self.buffer.clone() }, SpscConsumer { buffer: self.buffer })
+    }
+}
+
+impl<T> SpscProducer<T> {
+    pub fn push(&mut self, data: T) -> Result<(), T> {
+        unsafe { self.buffer.push(data) }
+    }
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> SpscConsumer<T> {
+    pub fn pop(&mut self) -> Option<T> {
+        unsafe { self.buffer.pop() }
+    }
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
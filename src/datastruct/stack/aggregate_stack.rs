@@ -0,0 +1,157 @@
+use std::marker::PhantomData;
+
+use crate::datastruct::stack::stack::Stack;
+
+pub trait Monoid<T> {
+    type Value: Clone + PartialEq;
+    fn identity(&self) -> Self::Value;
+    fn lift(&self, data: &T) -> Self::Value;
+    fn combine(&self, left: &Self::Value, right: &Self::Value) -> Self::Value;
+}
+
+#[derive(Debug, Clone)]
+pub struct FnMonoid<T, F> {
+    pub identity: T,
+    pub combine: F,
+}
+
+impl<T: Clone + PartialEq, F: Fn(&T, &T) -> T> Monoid<T> for FnMonoid<T, F> {
+    type Value = T;
+    fn identity(&self) -> T {
+        self.identity.clone()
+    }
+    fn lift(&self, data: &T) -> T {
+        data.clone()
+    }
+    fn combine(&self, left: &T, right: &T) -> T {
+        (self.combine)(left, right)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MinMonoid<T>(PhantomData<T>);
+
+#[derive(Debug, Clone)]
+pub struct MaxMonoid<T>(PhantomData<T>);
+
+impl<T> MinMonoid<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for MinMonoid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> MaxMonoid<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for MaxMonoid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialOrd + Clone> Monoid<T> for MinMonoid<T> {
+    type Value = Option<T>;
+    fn identity(&self) -> Option<T> {
+        None
+    }
+    fn lift(&self, data: &T) -> Option<T> {
+        Some(data.clone())
+    }
+    fn combine(&self, left: &Option<T>, right: &Option<T>) -> Option<T> {
+        match (left, right) {
+            (Some(l), Some(r)) => if r < l {Some(r.clone())} else {Some(l.clone())},
+            (Some(l), None) => Some(l.clone()),
+            (None, r) => r.clone(),
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone> Monoid<T> for MaxMonoid<T> {
+    type Value = Option<T>;
+    fn identity(&self) -> Option<T> {
+        None
+    }
+    fn lift(&self, data: &T) -> Option<T> {
+        Some(data.clone())
+    }
+    fn combine(&self, left: &Option<T>, right: &Option<T>) -> Option<T> {
+        match (left, right) {
+            (Some(l), Some(r)) => if r > l {Some(r.clone())} else {Some(l.clone())},
+            (Some(l), None) => Some(l.clone()),
+            (None, r) => r.clone(),
+        }
+    }
+}
+
+// every node keeps the fold of itself and everything below it
+#[derive(Debug, Clone)]
+pub struct AggregateStack<T: PartialEq, M: Monoid<T>> {
+    pub stack: Stack<(T, M::Value)>,
+    pub monoid: M,
+    // fold from top to bottom instead of bottom to top, used by AggregateQueue
+    pub(crate) reversed: bool,
+}
+
+pub type MinStack<T> = AggregateStack<T, MinMonoid<T>>;
+pub type MaxStack<T> = AggregateStack<T, MaxMonoid<T>>;
+
+impl<T: PartialEq, M: Monoid<T>> AggregateStack<T, M> {
+    pub fn new(monoid: M) -> Self {
+        Self {
+            stack: Stack::new(),
+            monoid,
+            reversed: false,
+        }
+    }
+    pub fn push(&mut self, data: T) {
+        let lifted = self.monoid.lift(&data);
+        let aggregate = match self.stack.peek() {
+            Some((_, below)) => if self.reversed {
+                self.monoid.combine(&lifted, below)
+            } else {
+                self.monoid.combine(below, &lifted)
+            },
+            None => lifted,
+        };
+        self.stack.push((data, aggregate));
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        self.stack.pop().map(|(data, _)| data)
+    }
+    pub fn peek(&self) -> Option<&T> {
+        self.stack.peek().map(|(data, _)| data)
+    }
+    pub fn aggregate(&self) -> M::Value {
+        match self.stack.peek() {
+            Some((_, aggregate)) => aggregate.clone(),
+            None => self.monoid.identity(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.stack.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.stack.len == 0
+    }
+}
+
+impl<T: PartialOrd + Clone> AggregateStack<T, MinMonoid<T>> {
+    pub fn min(&self) -> Option<&T> {
+        self.stack.peek().and_then(|(_, min)| min.as_ref())
+    }
+}
+
+impl<T: PartialOrd + Clone> AggregateStack<T, MaxMonoid<T>> {
+    pub fn max(&self) -> Option<&T> {
+        self.stack.peek().and_then(|(_, max)| max.as_ref())
+    }
+}
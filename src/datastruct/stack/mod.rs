@@ -1,4 +1,6 @@
 pub mod stack;
+pub mod monotonic_stack;
+pub mod aggregate_stack;
 
 #[cfg(test)]
 mod test_stack {
@@ -34,3 +36,105 @@ mod test_stack {
         assert_eq!(stack.peek(), None);
     }
 }
+
+#[cfg(test)]
+mod test_monotonic_stack {
+    use crate::datastruct::stack::monotonic_stack::*;
+    use rand::{Rng, thread_rng};
+    #[test]
+    fn test_push() {
+        let mut stack = MonotonicStack::new(MonotonicOrder::INCREASING);
+        assert_eq!(stack.push(3), vec![]);
+        assert_eq!(stack.push(5), vec![]);
+        assert_eq!(stack.push(5), vec![]);
+        assert_eq!(stack.push(4), vec![5, 5]);
+        assert_eq!(stack.push(1), vec![4, 3]);
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack.peek(), Some(&1));
+        let mut stack = MonotonicStack::new(MonotonicOrder::DECREASING);
+        assert_eq!(stack.push(3), vec![]);
+        assert_eq!(stack.push(1), vec![]);
+        assert_eq!(stack.push(2), vec![1]);
+        assert_eq!(stack.push(4), vec![2, 3]);
+        assert_eq!(stack.pop(), Some(4));
+        assert!(stack.is_empty());
+    }
+    #[test]
+    fn test_next_element() {
+        assert_eq!(next_greater_element(&[2, 1, 2, 4, 3]), vec![Some(3), Some(2), Some(3), None, None]);
+        assert_eq!(next_smaller_element(&[2, 1, 2, 4, 3]), vec![Some(1), None, None, Some(4), None]);
+        for _ in 0..100 {
+            let data: Vec<isize> = (0..200).map(|_| thread_rng().gen_range(0..20)).collect();
+            let greater: Vec<Option<usize>> = (0..data.len()).map(|i| (i + 1..data.len()).find(|&j| data[j] > data[i])).collect();
+            let smaller: Vec<Option<usize>> = (0..data.len()).map(|i| (i + 1..data.len()).find(|&j| data[j] < data[i])).collect();
+            assert_eq!(next_greater_element(&data), greater);
+            assert_eq!(next_smaller_element(&data), smaller);
+        }
+    }
+    #[test]
+    fn test_largest_rectangle_in_histogram() {
+        assert_eq!(largest_rectangle_in_histogram(&[]), 0);
+        assert_eq!(largest_rectangle_in_histogram(&[2, 1, 5, 6, 2, 3]), 10);
+        assert_eq!(largest_rectangle_in_histogram(&[2, 4]), 4);
+        assert_eq!(largest_rectangle_in_histogram(&[3, 3, 3]), 9);
+        for _ in 0..100 {
+            let heights: Vec<usize> = (0..100).map(|_| thread_rng().gen_range(0..10)).collect();
+            let mut standard = 0;
+            for i in 0..heights.len() {
+                let mut min = usize::MAX;
+                for (j, &height) in heights.iter().enumerate().skip(i) {
+                    min = min.min(height);
+                    standard = standard.max(min * (j - i + 1));
+                }
+            }
+            assert_eq!(largest_rectangle_in_histogram(&heights), standard);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_aggregate_stack {
+    use crate::datastruct::stack::aggregate_stack::*;
+    use rand::{Rng, thread_rng};
+    #[test]
+    fn test_min_max_stack() {
+        let mut min_stack = MinStack::new(MinMonoid::new());
+        let mut max_stack = MaxStack::new(MaxMonoid::new());
+        let mut standard = Vec::new();
+        assert_eq!(min_stack.min(), None);
+        assert_eq!(max_stack.max(), None);
+        for _ in 0..10000 {
+            if thread_rng().gen_ratio(2, 3) {
+                let n: isize = thread_rng().gen_range(-1000..1000);
+                min_stack.push(n);
+                max_stack.push(n);
+                standard.push(n);
+            } else {
+                let n = standard.pop();
+                assert_eq!(min_stack.pop(), n);
+                assert_eq!(max_stack.pop(), n);
+            }
+            assert_eq!(min_stack.min(), standard.iter().min());
+            assert_eq!(max_stack.max(), standard.iter().max());
+            assert_eq!(min_stack.aggregate(), standard.iter().min().cloned());
+            assert_eq!(min_stack.len(), standard.len());
+        }
+    }
+    #[test]
+    fn test_fn_monoid() {
+        let mut stack = AggregateStack::new(FnMonoid {identity: 0, combine: |a: &isize, b: &isize| a + b});
+        assert_eq!(stack.aggregate(), 0);
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(stack.aggregate(), 6);
+        assert_eq!(stack.peek(), Some(&3));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.aggregate(), 3);
+        let mut stack = AggregateStack::new(FnMonoid {identity: String::new(), combine: |a: &String, b: &String| format!("{}{}", a, b)});
+        stack.push("a".to_string());
+        stack.push("b".to_string());
+        stack.push("c".to_string());
+        assert_eq!(stack.aggregate(), "abc");
+    }
+}
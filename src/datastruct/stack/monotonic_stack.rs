@@ -0,0 +1,105 @@
+use crate::datastruct::stack::stack::Stack;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MonotonicOrder {
+    INCREASING,
+    DECREASING,
+}
+
+#[derive(Debug, Clone)]
+pub struct MonotonicStack<T: PartialOrd> {
+    pub stack: Stack<T>,
+    pub order: MonotonicOrder,
+}
+
+impl<T: PartialOrd> MonotonicStack<T> {
+    pub fn new(order: MonotonicOrder) -> Self {
+        Self {
+            stack: Stack::new(),
+            order,
+        }
+    }
+    // pops every element that would break the order and returns them, nearest first
+    pub fn push(&mut self, data: T) -> Vec<T> {
+        let mut popped = Vec::new();
+        while let Some(top) = self.stack.peek() {
+            let violate = match self.order {
+                MonotonicOrder::INCREASING => *top > data,
+                MonotonicOrder::DECREASING => *top < data,
+            };
+            if !violate {
+                break;
+            }
+            if let Some(top) = self.stack.pop() {
+                popped.push(top);
+            }
+        }
+        self.stack.push(data);
+        popped
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        self.stack.pop()
+    }
+    pub fn peek(&self) -> Option<&T> {
+        self.stack.peek()
+    }
+    pub fn len(&self) -> usize {
+        self.stack.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.stack.len == 0
+    }
+}
+
+struct Indexed<T: PartialOrd> {
+    data: T,
+    index: usize,
+}
+
+impl<T: PartialOrd> PartialEq for Indexed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for Indexed<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.data.partial_cmp(&other.data)
+    }
+}
+
+fn next_element_by<T: PartialOrd + Clone>(data: &[T], order: MonotonicOrder) -> Vec<Option<usize>> {
+    let mut result = vec![None; data.len()];
+    let mut stack = MonotonicStack::new(order);
+    for (index, n) in data.iter().enumerate() {
+        for popped in stack.push(Indexed {data: n.clone(), index}) {
+            result[popped.index] = Some(index);
+        }
+    }
+    result
+}
+
+pub fn next_greater_element<T: PartialOrd + Clone>(data: &[T]) -> Vec<Option<usize>> {
+    next_element_by(data, MonotonicOrder::DECREASING)
+}
+
+pub fn next_smaller_element<T: PartialOrd + Clone>(data: &[T]) -> Vec<Option<usize>> {
+    next_element_by(data, MonotonicOrder::INCREASING)
+}
+
+pub fn largest_rectangle_in_histogram(heights: &[usize]) -> usize {
+    let mut max_area = 0;
+    let mut stack = MonotonicStack::new(MonotonicOrder::INCREASING);
+    for index in 0..heights.len() + 1 {
+        let height = if index == heights.len() {0} else {heights[index]};
+        let popped = stack.push(Indexed {data: height, index});
+        for bar in popped.iter() {
+            max_area = max_area.max(bar.data * (index - bar.index));
+        }
+        // the new bar extends left over every taller bar it popped
+        if let (Some(leftmost), Some(top)) = (popped.last(), stack.stack.peek_mut()) {
+            top.index = leftmost.index;
+        }
+    }
+    max_area
+}
@@ -0,0 +1,144 @@
+use crate::datastruct::tire::ip_trie::{IpBits, bit_at, mask};
+
+// prefix holds the first len bits of the node, children only exist where the path branches or a value is stored
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompressedIpNode<V> {
+    pub prefix: u128,
+    pub len: u8,
+    pub value: Option<V>,
+    pub next: [Option<Box<CompressedIpNode<V>>>; 2],
+}
+
+impl<V> CompressedIpNode<V> {
+    pub fn new(bits: u128, len: u8) -> Self {
+        Self {
+            prefix: mask(bits, len),
+            len,
+            value: None,
+            next: [None, None],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompressedIpTrie<A: IpBits, V> {
+    pub len: usize,
+    pub root: CompressedIpNode<V>,
+    address: std::marker::PhantomData<A>,
+}
+
+impl<A: IpBits, V> CompressedIpTrie<A, V> {
+    pub fn new() -> Self {
+        Self {
+            len: 0,
+            root: CompressedIpNode::new(0, 0),
+            address: std::marker::PhantomData,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn insert(&mut self, address: A, prefix_len: u8, value: V) -> Option<V> {
+        fn recursive_insert<V>(node: &mut CompressedIpNode<V>, bits: u128, prefix_len: u8, value: V) -> Option<V> {
+            if node.len == prefix_len {
+                return node.value.replace(value);
+            }
+            let bit = bit_at(bits, node.len);
+            let child = match node.next[bit].as_mut() {
+                Some(child) => child,
+                None => {
+                    let mut leaf = CompressedIpNode::new(bits, prefix_len);
+                    leaf.value = Some(value);
+                    node.next[bit] = Some(Box::new(leaf));
+                    return None;
+                }
+            };
+            let common = ((bits ^ child.prefix).leading_zeros() as u8).min(prefix_len).min(child.len);
+            if common < child.len {
+                // split the edge at the first differing bit
+                let mut middle = Box::new(CompressedIpNode::new(bits, common));
+                std::mem::swap(child, &mut middle);
+                let child_bit = bit_at(middle.prefix, common);
+                child.next[child_bit] = Some(middle);
+            }
+            recursive_insert(child, bits, prefix_len, value)
+        }
+        assert!(prefix_len <= A::BITS, "prefix length out of range");
+        let old = recursive_insert(&mut self.root, mask(address.to_bits(), prefix_len), prefix_len, value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+    pub fn remove(&mut self, address: A, prefix_len: u8) -> Option<V> {
+        fn recursive_remove<V>(node: &mut CompressedIpNode<V>, bits: u128, prefix_len: u8) -> Option<V> {
+            if node.len == prefix_len {
+                return node.value.take();
+            }
+            let bit = bit_at(bits, node.len);
+            let child = match node.next[bit].as_mut() {
+                Some(child) if child.len <= prefix_len && mask(bits, child.len) == child.prefix => child,
+                _ => return None,
+            };
+            let value = recursive_remove(child, bits, prefix_len)?;
+            if child.value.is_none() {
+                // drop an empty leaf, or splice out a node that no longer branches
+                match child.next.iter().filter(|next| next.is_some()).count() {
+                    0 => node.next[bit] = None,
+                    1 => {
+                        let only = child.next.iter_mut().find_map(Option::take).expect("compressed ip trie get only child error");
+                        node.next[bit] = Some(only);
+                    }
+                    _ => (),
+                }
+            }
+            Some(value)
+        }
+        if prefix_len > A::BITS {
+            return None;
+        }
+        let value = recursive_remove(&mut self.root, mask(address.to_bits(), prefix_len), prefix_len);
+        if value.is_some() {
+            self.len -= 1;
+        }
+        value
+    }
+    pub fn get(&self, address: A, prefix_len: u8) -> Option<&V> {
+        if prefix_len > A::BITS {
+            return None;
+        }
+        let bits = mask(address.to_bits(), prefix_len);
+        let mut node = &self.root;
+        while node.len < prefix_len {
+            node = node.next[bit_at(bits, node.len)].as_ref()?;
+            if node.len > prefix_len || mask(bits, node.len) != node.prefix {
+                return None;
+            }
+        }
+        node.value.as_ref()
+    }
+    pub fn longest_match(&self, address: A) -> Option<(A, u8, &V)> {
+        let bits = address.to_bits();
+        let mut node = &self.root;
+        let mut best = node.value.as_ref().map(|value| (node.prefix, node.len, value));
+        while node.len < A::BITS {
+            match node.next[bit_at(bits, node.len)].as_ref() {
+                Some(next) if mask(bits, next.len) == next.prefix => node = next,
+                _ => break,
+            }
+            if let Some(value) = node.value.as_ref() {
+                best = Some((node.prefix, node.len, value));
+            }
+        }
+        best.map(|(prefix, len, value)| (A::from_bits(prefix), len, value))
+    }
+}
+
+impl<A: IpBits, V> Default for CompressedIpTrie<A, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
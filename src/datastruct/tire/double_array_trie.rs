@@ -0,0 +1,191 @@
+// a byte b moves along code b + 1, code 0 marks the end of a word
+const END_CODE: usize = 0;
+const FREE: i32 = -1;
+
+#[derive(Debug, PartialEq)]
+pub enum DoubleArrayError {
+    Unsorted(usize),
+    Truncated,
+    Corrupt,
+    // a state, word index or count does not fit the i32 / u32 slots
+    TooLarge,
+}
+
+// state t is a child of s when check[t] == s, t = base[s] + code
+// the end slot of a word stores -(word index) - 1 in base
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoubleArrayTrie {
+    pub base: Vec<i32>,
+    pub check: Vec<i32>,
+    pub words_count: usize,
+}
+
+impl DoubleArrayTrie {
+    // word i of the strictly increasing list gets the value i
+    pub fn from_sorted(words: &[&str]) -> Result<Self, DoubleArrayError> {
+        for i in 1..words.len() {
+            if words[i - 1] >= words[i] {
+                return Err(DoubleArrayError::Unsorted(i));
+            }
+        }
+        let mut trie = Self {
+            base: vec![0],
+            check: vec![0],
+            words_count: words.len(),
+        };
+        let mut next_free = 1;
+        if !words.is_empty() {
+            trie.build(0, words, 0, 0, &mut next_free)?;
+        }
+        while trie.check.len() > 1 && trie.check[trie.check.len() - 1] == FREE {
+            trie.check.pop();
+            trie.base.pop();
+        }
+        Ok(trie)
+    }
+    fn code(word: &str, depth: usize) -> usize {
+        match word.as_bytes().get(depth) {
+            Some(&byte) => byte as usize + 1,
+            None => END_CODE,
+        }
+    }
+    // words all share their first depth bytes and reach state, offset is the index of words[0]
+    fn build(&mut self, state: usize, words: &[&str], offset: usize, depth: usize, next_free: &mut usize) -> Result<(), DoubleArrayError> {
+        let mut siblings: Vec<(usize, usize, usize)> = Vec::new();
+        for (i, word) in words.iter().enumerate() {
+            let code = Self::code(word, depth);
+            match siblings.last_mut() {
+                Some((last, _, end)) if *last == code => *end = i + 1,
+                _ => siblings.push((code, i, i + 1)),
+            }
+        }
+        let first_code = siblings[0].0;
+        let mut begin = usize::max(*next_free, first_code + 1) - first_code;
+        loop {
+            let end = begin + siblings[siblings.len() - 1].0;
+            if end >= self.check.len() {
+                self.check.resize(end + 1, FREE);
+                self.base.resize(end + 1, 0);
+            }
+            if siblings.iter().all(|(code, _, _)| self.check[begin + code] == FREE) {
+                break;
+            }
+            begin += 1;
+        }
+        self.base[state] = i32::try_from(begin).map_err(|_| DoubleArrayError::TooLarge)?;
+        let parent = i32::try_from(state).map_err(|_| DoubleArrayError::TooLarge)?;
+        for (code, _, _) in siblings.iter() {
+            self.check[begin + code] = parent;
+        }
+        while *next_free < self.check.len() && self.check[*next_free] != FREE {
+            *next_free += 1;
+        }
+        for (code, start, end) in siblings {
+            if code == END_CODE {
+                let index = i32::try_from(offset + start).map_err(|_| DoubleArrayError::TooLarge)?;
+                self.base[begin] = -index - 1;
+            } else {
+                self.build(begin + code, &words[start..end], offset + start, depth + 1, next_free)?;
+            }
+        }
+        Ok(())
+    }
+    fn transition(&self, state: usize, code: usize) -> Option<usize> {
+        let base = self.base[state];
+        if base < 0 {
+            return None;
+        }
+        let next = base as usize + code;
+        if next < self.check.len() && self.check[next] == state as i32 {
+            Some(next)
+        } else {
+            None
+        }
+    }
+    fn value(&self, state: usize) -> Option<usize> {
+        let end = self.transition(state, END_CODE)?;
+        Some((-self.base[end] - 1) as usize)
+    }
+    pub fn len(&self) -> usize {
+        self.words_count
+    }
+    pub fn is_empty(&self) -> bool {
+        self.words_count == 0
+    }
+    // the index of word in the list the trie was built from
+    pub fn get(&self, word: &str) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut state = 0;
+        for &byte in word.as_bytes() {
+            state = self.transition(state, byte as usize + 1)?;
+        }
+        self.value(state)
+    }
+    // (length in bytes, word index) of every dictionary word that is a prefix of text, shortest first
+    pub fn common_prefix_search(&self, text: &str) -> Vec<(usize, usize)> {
+        let mut result = Vec::new();
+        if self.is_empty() {
+            return result;
+        }
+        let mut state = 0;
+        if let Some(value) = self.value(state) {
+            result.push((0, value));
+        }
+        for (i, &byte) in text.as_bytes().iter().enumerate() {
+            state = match self.transition(state, byte as usize + 1) {
+                Some(next) => next,
+                None => break,
+            };
+            if let Some(value) = self.value(state) {
+                result.push((i + 1, value));
+            }
+        }
+        result
+    }
+    // little endian: words count, array length, then base and check of every slot
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DoubleArrayError> {
+        let words_count = u32::try_from(self.words_count).map_err(|_| DoubleArrayError::TooLarge)?;
+        let len = u32::try_from(self.base.len()).map_err(|_| DoubleArrayError::TooLarge)?;
+        let mut bytes = Vec::with_capacity(8 + self.base.len() * 8);
+        bytes.extend(words_count.to_le_bytes());
+        bytes.extend(len.to_le_bytes());
+        for (base, check) in self.base.iter().zip(self.check.iter()) {
+            bytes.extend(base.to_le_bytes());
+            bytes.extend(check.to_le_bytes());
+        }
+        Ok(bytes)
+    }
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DoubleArrayError> {
+        let read = |index: usize| -> Result<[u8; 4], DoubleArrayError> {
+            match bytes.get(index * 4..index * 4 + 4) {
+                Some(word) => Ok([word[0], word[1], word[2], word[3]]),
+                None => Err(DoubleArrayError::Truncated),
+            }
+        };
+        let words_count = u32::from_le_bytes(read(0)?) as usize;
+        let len = u32::from_le_bytes(read(1)?) as usize;
+        if bytes.len() < 8 + len * 8 {
+            return Err(DoubleArrayError::Truncated);
+        }
+        if bytes.len() > 8 + len * 8 || len == 0 {
+            return Err(DoubleArrayError::Corrupt);
+        }
+        let mut trie = Self {
+            base: Vec::with_capacity(len),
+            check: Vec::with_capacity(len),
+            words_count,
+        };
+        for i in 0..len {
+            let base = i32::from_le_bytes(read(2 + i * 2)?);
+            let check = i32::from_le_bytes(read(3 + i * 2)?);
+            if check < FREE || check as i64 >= len as i64 || (base < 0 && -(base as i64) > words_count as i64) {
+                return Err(DoubleArrayError::Corrupt);
+            }
+            trie.base.push(base);
+            trie.check.push(check);
+        }
+        Ok(trie)
+    }
+}
@@ -0,0 +1,150 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+// addresses are kept left aligned in a u128, so bit 0 is always the most significant bit
+pub trait IpBits: Copy {
+    const BITS: u8;
+    fn to_bits(self) -> u128;
+    fn from_bits(bits: u128) -> Self;
+}
+
+impl IpBits for Ipv4Addr {
+    const BITS: u8 = 32;
+    fn to_bits(self) -> u128 {
+        (u32::from(self) as u128) << 96
+    }
+    fn from_bits(bits: u128) -> Self {
+        Ipv4Addr::from((bits >> 96) as u32)
+    }
+}
+
+impl IpBits for Ipv6Addr {
+    const BITS: u8 = 128;
+    fn to_bits(self) -> u128 {
+        u128::from(self)
+    }
+    fn from_bits(bits: u128) -> Self {
+        Ipv6Addr::from(bits)
+    }
+}
+
+// keep the first len bits
+pub(crate) fn mask(bits: u128, len: u8) -> u128 {
+    if len == 0 {0} else {bits & (u128::MAX << (128 - len as u32))}
+}
+
+pub(crate) fn bit_at(bits: u128, index: u8) -> usize {
+    ((bits >> (127 - index as u32)) & 1) as usize
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpTrieNode<V> {
+    pub value: Option<V>,
+    pub next: [Option<Box<IpTrieNode<V>>>; 2],
+}
+
+impl<V> IpTrieNode<V> {
+    pub fn new() -> Self {
+        Self {
+            value: None,
+            next: [None, None],
+        }
+    }
+}
+
+impl<V> Default for IpTrieNode<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// one level per bit, host bits past the prefix length are ignored
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpTrie<A: IpBits, V> {
+    pub len: usize,
+    pub root: IpTrieNode<V>,
+    address: std::marker::PhantomData<A>,
+}
+
+impl<A: IpBits, V> IpTrie<A, V> {
+    pub fn new() -> Self {
+        Self {
+            len: 0,
+            root: IpTrieNode::new(),
+            address: std::marker::PhantomData,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn insert(&mut self, address: A, prefix_len: u8, value: V) -> Option<V> {
+        assert!(prefix_len <= A::BITS, "prefix length out of range");
+        let bits = address.to_bits();
+        let mut node = &mut self.root;
+        for index in 0..prefix_len {
+            node = node.next[bit_at(bits, index)].get_or_insert_with(Default::default);
+        }
+        let old = node.value.replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+    pub fn remove(&mut self, address: A, prefix_len: u8) -> Option<V> {
+        fn recursive_remove<V>(node: &mut IpTrieNode<V>, bits: u128, index: u8, prefix_len: u8) -> Option<V> {
+            if index == prefix_len {
+                return node.value.take();
+            }
+            let bit = bit_at(bits, index);
+            let next = node.next[bit].as_mut()?;
+            let value = recursive_remove(next, bits, index + 1, prefix_len);
+            if value.is_some() && next.value.is_none() && next.next.iter().all(Option::is_none) {
+                node.next[bit] = None;
+            }
+            value
+        }
+        if prefix_len > A::BITS {
+            return None;
+        }
+        let value = recursive_remove(&mut self.root, address.to_bits(), 0, prefix_len);
+        if value.is_some() {
+            self.len -= 1;
+        }
+        value
+    }
+    pub fn get(&self, address: A, prefix_len: u8) -> Option<&V> {
+        if prefix_len > A::BITS {
+            return None;
+        }
+        let bits = address.to_bits();
+        let mut node = &self.root;
+        for index in 0..prefix_len {
+            node = node.next[bit_at(bits, index)].as_ref()?;
+        }
+        node.value.as_ref()
+    }
+    // the most specific prefix containing address
+    pub fn longest_match(&self, address: A) -> Option<(A, u8, &V)> {
+        let bits = address.to_bits();
+        let mut node = &self.root;
+        let mut best = node.value.as_ref().map(|value| (0, value));
+        for index in 0..A::BITS {
+            match node.next[bit_at(bits, index)].as_ref() {
+                Some(next) => node = next,
+                None => break,
+            }
+            if let Some(value) = node.value.as_ref() {
+                best = Some((index + 1, value));
+            }
+        }
+        best.map(|(len, value)| (A::from_bits(mask(bits, len)), len, value))
+    }
+}
+
+impl<A: IpBits, V> Default for IpTrie<A, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,8 +1,16 @@
 pub mod tire;
+pub mod trie_map;
+pub mod radix_tree;
+pub mod ip_trie;
+pub mod compressed_ip_trie;
+pub mod double_array_trie;
+pub mod ternary_search_tree;
+pub mod serialize;
 
 #[cfg(test)]
 mod test_tire {
     use crate::datastruct::tire::tire::Trie;
+    use rand::{Rng, thread_rng};
     const WORD1: &str = "hello";
     const WORD2: &str = "world";
     const WORD3: &str = "apple";
@@ -64,4 +72,741 @@ mod test_tire {
         tire.insert_word(PREFIX1);
         assert_eq!(tire.query_prefix(PREFIX1).sort(), vec![WORD4.to_string(), WORD3.to_string(), PREFIX1.to_string()].sort());
     }
+    #[test]
+    fn test_iter() {
+        let mut tire = Trie::new();
+        assert_eq!(tire.iter().next(), None);
+        tire.insert_word(WORD1);
+        tire.insert_word(WORD2);
+        tire.insert_word(WORD3);
+        tire.insert_word(WORD4);
+        tire.insert_word(PREFIX1);
+        tire.insert_word(WORD3);
+        let words: Vec<(String, usize)> = tire.iter().collect();
+        assert_eq!(words, vec![(PREFIX1.to_string(), 1), (WORD3.to_string(), 2), (WORD4.to_string(), 1), (WORD1.to_string(), 1), (WORD2.to_string(), 1)]);
+        let words: Vec<String> = tire.iter_prefix("appl").map(|(word, _)| word).collect();
+        assert_eq!(words, vec![WORD3.to_string(), WORD4.to_string()]);
+        assert_eq!(tire.iter_prefix("b").next(), None);
+        let mut standard: Vec<String> = Vec::new();
+        let mut tire = Trie::new();
+        for _ in 0..500 {
+            let word: String = (0..thread_rng().gen_range(1..6)).map(|_| thread_rng().gen_range('a'..='d')).collect();
+            tire.insert_word(&word);
+            standard.push(word);
+        }
+        standard.sort();
+        standard.dedup();
+        assert_eq!(tire.iter().map(|(word, _)| word).collect::<Vec<String>>(), standard);
+    }
+    fn edit_distance(a: &str, b: &str) -> usize {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        let mut dp = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for i in 0..=a.len() {
+            for j in 0..=b.len() {
+                dp[i][j] = if i == 0 {
+                    j
+                } else if j == 0 {
+                    i
+                } else {
+                    let replace = dp[i - 1][j - 1] + if a[i - 1] == b[j - 1] {0} else {1};
+                    replace.min(dp[i - 1][j] + 1).min(dp[i][j - 1] + 1)
+                };
+            }
+        }
+        dp[a.len()][b.len()]
+    }
+    #[test]
+    fn test_search_within() {
+        let mut tire = Trie::new();
+        for word in [WORD1, WORD2, WORD3, WORD4, PREFIX1, "help", "hell"] {
+            tire.insert_word(word);
+        }
+        assert_eq!(tire.search_within("hello", 0), vec![(WORD1.to_string(), 0)]);
+        assert_eq!(tire.search_within("helo", 1), vec![("hell".to_string(), 1), (WORD1.to_string(), 1), ("help".to_string(), 1)]);
+        assert_eq!(tire.search_within("aple", 2), vec![(WORD3.to_string(), 1), (PREFIX1.to_string(), 2)]);
+        assert_eq!(tire.search_within("", 3), vec![(PREFIX1.to_string(), 3)]);
+        let mut tire = Trie::new();
+        let mut words: Vec<String> = Vec::new();
+        for _ in 0..300 {
+            let word: String = (0..thread_rng().gen_range(1..7)).map(|_| thread_rng().gen_range('a'..='c')).collect();
+            tire.insert_word(&word);
+            words.push(word);
+        }
+        words.sort();
+        words.dedup();
+        for _ in 0..20 {
+            let query: String = (0..thread_rng().gen_range(0..7)).map(|_| thread_rng().gen_range('a'..='d')).collect();
+            let max_edits = thread_rng().gen_range(0..3);
+            let mut standard: Vec<(String, usize)> = words.iter()
+                .map(|word| (word.clone(), edit_distance(word, &query)))
+                .filter(|(_, distance)| *distance <= max_edits)
+                .collect();
+            standard.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+            assert_eq!(tire.search_within(&query, max_edits), standard);
+        }
+    }
+    #[test]
+    fn test_search_wildcard() {
+        let mut tire = Trie::new();
+        for word in [WORD1, WORD2, WORD3, WORD4, PREFIX1, "help", "hell"] {
+            tire.insert_word(word);
+        }
+        assert_eq!(tire.search_wildcard("hel?"), vec!["hell".to_string(), "help".to_string()]);
+        assert_eq!(tire.search_wildcard("hel*"), vec!["hell".to_string(), WORD1.to_string(), "help".to_string()]);
+        assert_eq!(tire.search_wildcard("app*"), vec![PREFIX1.to_string(), WORD3.to_string(), WORD4.to_string()]);
+        assert_eq!(tire.search_wildcard("*l?"), vec![WORD3.to_string(), "hell".to_string(), WORD1.to_string(), "help".to_string(), WORD2.to_string()]);
+        assert_eq!(tire.search_wildcard("a**n"), vec![WORD4.to_string()]);
+        assert_eq!(tire.search_wildcard("?????"), vec![WORD3.to_string(), WORD1.to_string(), WORD2.to_string()]);
+        assert_eq!(tire.search_wildcard("x*"), Vec::<String>::new());
+        assert_eq!(tire.search_wildcard("*").len(), 7);
+        assert_eq!(tire.search_wildcard("*p*l*"), vec![WORD3.to_string(), WORD4.to_string()]);
+    }
+    #[test]
+    fn test_search_wildcard_repeated_star() {
+        // every split of a long run of one letter between the stars is a separate way to match
+        let mut tire = Trie::new();
+        let long = "a".repeat(60);
+        tire.insert_word(&long);
+        tire.insert_word(&(long.clone() + "b"));
+        assert_eq!(tire.search_wildcard("a*a*a*a*a*a*a*a*b"), vec![long.clone() + "b"]);
+        assert_eq!(tire.search_wildcard("*a*a*a*a*a*a*a*a*"), vec![long.clone(), long + "b"]);
+        assert_eq!(tire.search_wildcard("a*a*a*a*a*a*a*a*c"), Vec::<String>::new());
+    }
+    #[test]
+    fn test_top_k_with_prefix() {
+        let mut tire = Trie::new();
+        for (word, count) in [("car", 5), ("card", 2), ("care", 5), ("cart", 7), ("cat", 9), ("dog", 10)] {
+            for _ in 0..count {
+                tire.insert_word(word);
+            }
+        }
+        assert_eq!(tire.top_k_with_prefix("car", 3), vec![("cart".to_string(), 7), ("car".to_string(), 5), ("care".to_string(), 5)]);
+        assert_eq!(tire.top_k_with_prefix("", 2), vec![("dog".to_string(), 10), ("cat".to_string(), 9)]);
+        assert_eq!(tire.top_k_with_prefix("card", 5), vec![("card".to_string(), 2)]);
+        assert_eq!(tire.top_k_with_prefix("x", 5), vec![]);
+        assert_eq!(tire.top_k_with_prefix("c", 0), vec![]);
+        let mut tire = Trie::new();
+        for _ in 0..2000 {
+            let word: String = (0..thread_rng().gen_range(1..5)).map(|_| thread_rng().gen_range('a'..='c')).collect();
+            tire.insert_word(&word);
+        }
+        for _ in 0..1000 {
+            let word: String = (0..thread_rng().gen_range(1..5)).map(|_| thread_rng().gen_range('a'..='c')).collect();
+            tire.delete_word(&word);
+        }
+        for prefix in ["", "a", "ab", "cc"] {
+            let mut standard: Vec<(String, usize)> = tire.iter_prefix(prefix).collect();
+            standard.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+            standard.truncate(10);
+            assert_eq!(tire.top_k_with_prefix(prefix, 10), standard);
+        }
+    }
+    #[test]
+    fn test_top_k_with_prefix_ties() {
+        let mut tire = Trie::new();
+        for word in ["b", "ab", "abc", "a", "ba", "bab"] {
+            tire.insert_word(word);
+            tire.insert_word(word);
+        }
+        tire.insert_word("zz");
+        // equal counts come back in lexicographic order, a word before its extensions
+        let all = vec![("a".to_string(), 2), ("ab".to_string(), 2), ("abc".to_string(), 2), ("b".to_string(), 2), ("ba".to_string(), 2), ("bab".to_string(), 2)];
+        assert_eq!(tire.top_k_with_prefix("", 6), all);
+        assert_eq!(tire.top_k_with_prefix("", 3), all[..3].to_vec());
+        // k larger than the number of matches gives every match
+        assert_eq!(tire.top_k_with_prefix("b", 100), all[3..].to_vec());
+        assert_eq!(tire.top_k_with_prefix("", 100).len(), 7);
+        assert_eq!(tire.top_k_with_prefix("", 100).last(), Some(&("zz".to_string(), 1)));
+        // removing the best word lowers the cached maxima
+        tire.delete_word("abc");
+        tire.delete_word("abc");
+        tire.delete_word("ab");
+        tire.insert_word("zz");
+        tire.insert_word("zz");
+        assert_eq!(tire.top_k_with_prefix("", 2), vec![("zz".to_string(), 3), ("a".to_string(), 2)]);
+        assert_eq!(tire.top_k_with_prefix("ab", 5), vec![("ab".to_string(), 1)]);
+    }
+}
+
+#[cfg(test)]
+mod test_trie_map {
+    use std::collections::HashMap;
+    use crate::datastruct::tire::trie_map::{Entry, TrieMap};
+    use rand::{Rng, thread_rng};
+    #[test]
+    fn test_insert_get_remove() {
+        let mut map = TrieMap::new();
+        assert_eq!(map.insert("apple".chars(), 1), None);
+        assert_eq!(map.insert("app".chars(), 2), None);
+        assert_eq!(map.insert("".chars(), 0), None);
+        assert_eq!(map.insert("apple".chars(), 3), Some(1));
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get("apple".chars()), Some(&3));
+        assert_eq!(map.get("ap".chars()), None);
+        assert_eq!(map.get("".chars()), Some(&0));
+        if let Some(value) = map.get_mut("app".chars()) {
+            *value += 10;
+        }
+        assert_eq!(map.get("app".chars()), Some(&12));
+        assert_eq!(map.remove("ap".chars()), None);
+        assert_eq!(map.remove("apple".chars()), Some(3));
+        assert_eq!(map.remove("apple".chars()), None);
+        assert!(map.contains_key("app".chars()));
+        assert_eq!(map.remove("app".chars()), Some(12));
+        assert_eq!(map.remove("".chars()), Some(0));
+        assert!(map.is_empty());
+        assert_eq!(map, TrieMap::new());
+    }
+    #[test]
+    fn test_path_segments() {
+        let mut map = TrieMap::new();
+        map.insert("usr/local/bin".split('/'), "binaries");
+        map.insert("usr/local".split('/'), "local");
+        map.insert(vec!["etc"], "config");
+        assert_eq!(map.get("usr/local".split('/')), Some(&"local"));
+        assert_eq!(map.get(vec!["usr"]), None);
+        assert_eq!(map.remove("usr/local/bin".split('/')), Some("binaries"));
+        assert_eq!(map.get("usr/local".split('/')), Some(&"local"));
+    }
+    #[test]
+    fn test_entry() {
+        let mut map: TrieMap<u8, usize> = TrieMap::new();
+        for word in ["to", "tea", "ten", "to", "inn", "to"] {
+            *map.entry(word.bytes()).or_insert(0) += 1;
+        }
+        assert_eq!(map.get("to".bytes()), Some(&3));
+        assert_eq!(map.get("tea".bytes()), Some(&1));
+        assert_eq!(map.len(), 4);
+        map.entry("tea".bytes()).and_modify(|v| *v = 7).or_default();
+        map.entry("te".bytes()).and_modify(|v| *v = 7).or_default();
+        assert_eq!(map.get("tea".bytes()), Some(&7));
+        assert_eq!(map.get("te".bytes()), Some(&0));
+        match map.entry("ten".bytes()) {
+            Entry::Occupied(mut entry) => assert_eq!(entry.insert(5), 1),
+            Entry::Vacant(_) => panic!("ten should be occupied"),
+        }
+        match map.entry("tent".bytes()) {
+            Entry::Occupied(_) => panic!("tent should be vacant"),
+            Entry::Vacant(entry) => assert_eq!(*entry.insert(9), 9),
+        }
+        assert_eq!(map.get("ten".bytes()), Some(&5));
+        assert_eq!(map.len(), 6);
+    }
+    #[test]
+    fn test_random() {
+        let mut map = TrieMap::new();
+        let mut standard = HashMap::new();
+        for _ in 0..5000 {
+            let key: Vec<u8> = (0..thread_rng().gen_range(0..4)).map(|_| thread_rng().gen_range(0..3)).collect();
+            let value: usize = thread_rng().gen();
+            match thread_rng().gen_range(0..3) {
+                0 => assert_eq!(map.insert(key.clone(), value), standard.insert(key, value)),
+                1 => assert_eq!(map.remove(key.clone()), standard.remove(&key)),
+                _ => assert_eq!(map.get(key.clone()), standard.get(&key)),
+            }
+            assert_eq!(map.len(), standard.len());
+        }
+        for (key, value) in standard.iter() {
+            assert_eq!(map.remove(key.clone()), Some(*value));
+        }
+        assert_eq!(map, TrieMap::new());
+    }
+}
+
+#[cfg(test)]
+mod test_radix_tree {
+    use std::collections::BTreeMap;
+    use crate::datastruct::tire::radix_tree::{RadixNode, RadixTree};
+    use rand::{Rng, thread_rng};
+    // every node other than the root holds a word or branches
+    fn check_compressed(node: &RadixNode, is_root: bool) {
+        if !is_root {
+            assert!(!node.label.is_empty());
+            assert!(node.count > 0 || node.next.len() > 1);
+        }
+        for (c, child) in node.next.iter() {
+            assert_eq!(child.label.chars().next(), Some(*c));
+            check_compressed(child, false);
+        }
+    }
+    #[test]
+    fn test_insert_delete() {
+        let mut tree = RadixTree::new();
+        tree.insert_word("/usr/local/bin");
+        tree.insert_word("/usr/local/lib");
+        tree.insert_word("/usr/lib");
+        tree.insert_word("/usr");
+        check_compressed(&tree.root, true);
+        assert_eq!(tree.root.next.len(), 1);
+        assert_eq!(tree.root.next[&'/'].label, "/usr");
+        assert!(tree.query_word("/usr"));
+        assert!(!tree.query_word("/us"));
+        assert!(!tree.query_word("/usr/local"));
+        let standard = tree.clone();
+        tree.insert_word("/usr/loc");
+        assert_ne!(tree, standard);
+        assert!(tree.delete_word("/usr/loc"));
+        assert_eq!(tree, standard);
+        assert!(!tree.delete_word("/usr/local"));
+        assert!(tree.delete_word("/usr/local/bin"));
+        check_compressed(&tree.root, true);
+        assert!(tree.query_word("/usr/local/lib"));
+        assert!(tree.delete_word("/usr/local/lib"));
+        assert!(tree.delete_word("/usr/lib"));
+        assert!(tree.delete_word("/usr"));
+        assert_eq!(tree, RadixTree::new());
+    }
+    #[test]
+    fn test_query_prefix() {
+        let mut tree = RadixTree::new();
+        for word in ["romane", "romanus", "romulus", "rubens", "ruber", "rubicon", "rubicundus", "rom"] {
+            tree.insert_word(word);
+        }
+        assert_eq!(tree.query_prefix("rom"), vec!["rom", "romane", "romanus", "romulus"]);
+        assert_eq!(tree.query_prefix("ro"), vec!["rom", "romane", "romanus", "romulus"]);
+        assert_eq!(tree.query_prefix("rubic"), vec!["rubicon", "rubicundus"]);
+        assert_eq!(tree.query_prefix("rube"), vec!["rubens", "ruber"]);
+        assert_eq!(tree.query_prefix("x"), Vec::<String>::new());
+        assert_eq!(tree.query_prefix("romx"), Vec::<String>::new());
+        assert_eq!(tree.query_prefix("").len(), 8);
+    }
+    #[test]
+    fn test_longest_prefix_match() {
+        let mut tree = RadixTree::new();
+        tree.insert_word("/api");
+        tree.insert_word("/api/v1");
+        tree.insert_word("/api/v1/users");
+        tree.insert_word("/static");
+        assert_eq!(tree.longest_prefix_match("/api/v1/users/42"), Some("/api/v1/users"));
+        assert_eq!(tree.longest_prefix_match("/api/v1/user"), Some("/api/v1"));
+        assert_eq!(tree.longest_prefix_match("/api/v2"), Some("/api"));
+        assert_eq!(tree.longest_prefix_match("/ap"), None);
+        assert_eq!(tree.longest_prefix_match("/static/app.js"), Some("/static"));
+        tree.insert_word("");
+        assert_eq!(tree.longest_prefix_match("/other"), Some(""));
+    }
+    #[test]
+    fn test_random() {
+        let mut tree = RadixTree::new();
+        let mut standard: BTreeMap<String, usize> = BTreeMap::new();
+        for _ in 0..3000 {
+            let word: String = (0..thread_rng().gen_range(0..6)).map(|_| thread_rng().gen_range('a'..='c')).collect();
+            if thread_rng().gen_ratio(3, 5) {
+                tree.insert_word(&word);
+                *standard.entry(word).or_insert(0) += 1;
+            } else {
+                let expect = match standard.get_mut(&word) {
+                    Some(count) => {
+                        *count -= 1;
+                        if *count == 0 {
+                            standard.remove(&word);
+                        }
+                        true
+                    }
+                    None => false,
+                };
+                assert_eq!(tree.delete_word(&word), expect);
+            }
+            check_compressed(&tree.root, true);
+            assert_eq!(tree.words_count, standard.values().sum());
+        }
+        let prefix: String = (0..2).map(|_| thread_rng().gen_range('a'..='c')).collect();
+        let expect: Vec<String> = standard.keys().filter(|word| word.starts_with(&prefix)).cloned().collect();
+        assert_eq!(tree.query_prefix(&prefix), expect);
+        for word in standard.keys() {
+            assert!(tree.query_word(word));
+            assert_eq!(tree.longest_prefix_match(&format!("{}d", word)), Some(word.as_str()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_ip_trie {
+    use std::collections::HashMap;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use crate::datastruct::tire::ip_trie::{IpBits, IpTrie, mask};
+    use crate::datastruct::tire::compressed_ip_trie::{CompressedIpNode, CompressedIpTrie};
+    use rand::{Rng, thread_rng};
+    fn routes() -> Vec<(Ipv4Addr, u8, &'static str)> {
+        vec![
+            (Ipv4Addr::new(0, 0, 0, 0), 0, "default"),
+            (Ipv4Addr::new(10, 0, 0, 0), 8, "10/8"),
+            (Ipv4Addr::new(10, 1, 0, 0), 16, "10.1/16"),
+            (Ipv4Addr::new(10, 1, 2, 0), 24, "10.1.2/24"),
+            (Ipv4Addr::new(10, 1, 2, 3), 32, "host"),
+            (Ipv4Addr::new(192, 168, 0, 0), 16, "192.168/16"),
+        ]
+    }
+    fn check_compressed<V>(node: &CompressedIpNode<V>, is_root: bool) {
+        let children = node.next.iter().filter(|next| next.is_some()).count();
+        if !is_root {
+            assert!(node.value.is_some() || children == 2);
+        }
+        for next in node.next.iter().flatten() {
+            assert!(next.len > node.len);
+            assert_eq!(mask(next.prefix, node.len), node.prefix);
+            check_compressed(next, false);
+        }
+    }
+    #[test]
+    fn test_overlapping_prefixes() {
+        let mut trie = IpTrie::new();
+        let mut compressed = CompressedIpTrie::new();
+        for (address, len, name) in routes() {
+            assert_eq!(trie.insert(address, len, name), None);
+            assert_eq!(compressed.insert(address, len, name), None);
+        }
+        check_compressed(&compressed.root, true);
+        assert_eq!(trie.insert(Ipv4Addr::new(10, 1, 255, 255), 16, "10.1/16 again"), Some("10.1/16"));
+        assert_eq!(compressed.insert(Ipv4Addr::new(10, 1, 255, 255), 16, "10.1/16 again"), Some("10.1/16"));
+        assert_eq!(trie.len(), 6);
+        assert_eq!(compressed.len(), 6);
+        let cases = [
+            (Ipv4Addr::new(10, 1, 2, 3), Some((Ipv4Addr::new(10, 1, 2, 3), 32))),
+            (Ipv4Addr::new(10, 1, 2, 4), Some((Ipv4Addr::new(10, 1, 2, 0), 24))),
+            (Ipv4Addr::new(10, 1, 3, 4), Some((Ipv4Addr::new(10, 1, 0, 0), 16))),
+            (Ipv4Addr::new(10, 2, 3, 4), Some((Ipv4Addr::new(10, 0, 0, 0), 8))),
+            (Ipv4Addr::new(192, 168, 1, 1), Some((Ipv4Addr::new(192, 168, 0, 0), 16))),
+            (Ipv4Addr::new(8, 8, 8, 8), Some((Ipv4Addr::new(0, 0, 0, 0), 0))),
+        ];
+        for (address, expect) in cases {
+            assert_eq!(trie.longest_match(address).map(|(a, l, _)| (a, l)), expect);
+            assert_eq!(compressed.longest_match(address).map(|(a, l, _)| (a, l)), expect);
+        }
+        assert_eq!(trie.get(Ipv4Addr::new(10, 1, 2, 0), 24), Some(&"10.1.2/24"));
+        assert_eq!(compressed.get(Ipv4Addr::new(10, 1, 2, 0), 24), Some(&"10.1.2/24"));
+        assert_eq!(trie.get(Ipv4Addr::new(10, 1, 2, 0), 23), None);
+        assert_eq!(compressed.get(Ipv4Addr::new(10, 1, 2, 0), 23), None);
+        assert_eq!(trie.remove(Ipv4Addr::new(10, 1, 0, 0), 16), Some("10.1/16 again"));
+        assert_eq!(compressed.remove(Ipv4Addr::new(10, 1, 0, 0), 16), Some("10.1/16 again"));
+        assert_eq!(compressed.remove(Ipv4Addr::new(10, 1, 0, 0), 16), None);
+        check_compressed(&compressed.root, true);
+        assert_eq!(trie.longest_match(Ipv4Addr::new(10, 1, 3, 4)).map(|(_, l, v)| (l, *v)), Some((8, "10/8")));
+        assert_eq!(compressed.longest_match(Ipv4Addr::new(10, 1, 3, 4)).map(|(_, l, v)| (l, *v)), Some((8, "10/8")));
+        assert_eq!(trie.remove(Ipv4Addr::new(0, 0, 0, 0), 0), Some("default"));
+        assert_eq!(compressed.remove(Ipv4Addr::new(0, 0, 0, 0), 0), Some("default"));
+        assert_eq!(trie.longest_match(Ipv4Addr::new(8, 8, 8, 8)), None);
+        assert_eq!(compressed.longest_match(Ipv4Addr::new(8, 8, 8, 8)), None);
+        for (address, len, _) in routes() {
+            trie.remove(address, len);
+            compressed.remove(address, len);
+        }
+        assert_eq!(trie, IpTrie::new());
+        assert_eq!(compressed, CompressedIpTrie::new());
+    }
+    fn brute_force<A: IpBits>(routes: &HashMap<(u128, u8), usize>, address: A) -> Option<(u8, usize)> {
+        let bits = address.to_bits();
+        routes.iter()
+            .filter(|((prefix, len), _)| mask(bits, *len) == *prefix)
+            .max_by_key(|((_, len), _)| *len)
+            .map(|((_, len), value)| (*len, *value))
+    }
+    fn check_random<A: IpBits + std::fmt::Debug + PartialEq>(random_address: impl Fn() -> A) {
+        let mut trie = IpTrie::new();
+        let mut compressed = CompressedIpTrie::new();
+        let mut standard: HashMap<(u128, u8), usize> = HashMap::new();
+        let mut inserted: Vec<(A, u8)> = Vec::new();
+        for value in 0..2000 {
+            if inserted.is_empty() || thread_rng().gen_ratio(2, 3) {
+                let address = random_address();
+                let len = thread_rng().gen_range(0..=A::BITS);
+                let key = (mask(address.to_bits(), len), len);
+                let expect = standard.insert(key, value);
+                assert_eq!(trie.insert(address, len, value), expect);
+                assert_eq!(compressed.insert(address, len, value), expect);
+                inserted.push((address, len));
+            } else {
+                let (address, len) = inserted.swap_remove(thread_rng().gen_range(0..inserted.len()));
+                let key = (mask(address.to_bits(), len), len);
+                let expect = standard.remove(&key);
+                assert_eq!(trie.remove(address, len), expect);
+                assert_eq!(compressed.remove(address, len), expect);
+            }
+            assert_eq!(trie.len(), standard.len());
+            assert_eq!(compressed.len(), standard.len());
+            let address = random_address();
+            let expect = brute_force(&standard, address);
+            assert_eq!(trie.longest_match(address).map(|(_, l, v)| (l, *v)), expect);
+            assert_eq!(compressed.longest_match(address).map(|(_, l, v)| (l, *v)), expect);
+        }
+        check_compressed(&compressed.root, true);
+        for ((prefix, len), value) in standard.iter() {
+            assert_eq!(trie.get(A::from_bits(*prefix), *len), Some(value));
+            assert_eq!(compressed.get(A::from_bits(*prefix), *len), Some(value));
+        }
+    }
+    #[test]
+    fn test_random_ipv4() {
+        // a small address space so that prefixes overlap often
+        check_random(|| Ipv4Addr::new(10, thread_rng().gen_range(0..4), thread_rng().gen(), thread_rng().gen()));
+    }
+    #[test]
+    fn test_random_ipv6() {
+        check_random(|| Ipv6Addr::new(0x2001, 0xdb8, thread_rng().gen_range(0..4), 0, 0, 0, 0, thread_rng().gen()));
+    }
+}
+
+#[cfg(test)]
+mod test_double_array_trie {
+    use crate::datastruct::tire::double_array_trie::{DoubleArrayError, DoubleArrayTrie};
+    use rand::{Rng, thread_rng};
+    const WORDS: [&str; 7] = ["a", "ab", "abc", "b", "bcd", "cafe", "caf\u{e9}"];
+    #[test]
+    fn test_lookup() {
+        let trie = DoubleArrayTrie::from_sorted(&WORDS).unwrap();
+        assert_eq!(trie.len(), 7);
+        for (i, word) in WORDS.iter().enumerate() {
+            assert_eq!(trie.get(word), Some(i));
+        }
+        assert_eq!(trie.get(""), None);
+        assert_eq!(trie.get("bc"), None);
+        assert_eq!(trie.get("abcd"), None);
+        assert_eq!(trie.get("caf"), None);
+        assert_eq!(trie.common_prefix_search("abcde"), vec![(1, 0), (2, 1), (3, 2)]);
+        assert_eq!(trie.common_prefix_search("bc"), vec![(1, 3)]);
+        assert_eq!(trie.common_prefix_search("caf\u{e9}s"), vec![(5, 6)]);
+        assert_eq!(trie.common_prefix_search("xyz"), vec![]);
+        let trie = DoubleArrayTrie::from_sorted(&["", "x"]).unwrap();
+        assert_eq!(trie.get(""), Some(0));
+        assert_eq!(trie.common_prefix_search("xy"), vec![(0, 0), (1, 1)]);
+        let trie = DoubleArrayTrie::from_sorted(&[]).unwrap();
+        assert!(trie.is_empty());
+        assert_eq!(trie.get("a"), None);
+        assert_eq!(DoubleArrayTrie::from_sorted(&["b", "a"]), Err(DoubleArrayError::Unsorted(1)));
+        assert_eq!(DoubleArrayTrie::from_sorted(&["a", "b", "b"]), Err(DoubleArrayError::Unsorted(2)));
+    }
+    #[test]
+    fn test_random() {
+        let mut words: Vec<String> = (0..3000)
+            .map(|_| (0..thread_rng().gen_range(1..8)).map(|_| thread_rng().gen_range('a'..='f')).collect())
+            .collect();
+        words.sort();
+        words.dedup();
+        let words: Vec<&str> = words.iter().map(|word| word.as_str()).collect();
+        let trie = DoubleArrayTrie::from_sorted(&words).unwrap();
+        for (i, word) in words.iter().enumerate() {
+            assert_eq!(trie.get(word), Some(i));
+        }
+        for _ in 0..1000 {
+            let text: String = (0..thread_rng().gen_range(0..10)).map(|_| thread_rng().gen_range('a'..='g')).collect();
+            assert_eq!(trie.get(&text), words.binary_search(&text.as_str()).ok());
+            let standard: Vec<(usize, usize)> = (0..=text.len())
+                .filter_map(|len| words.binary_search(&&text[..len]).ok().map(|i| (len, i)))
+                .collect();
+            assert_eq!(trie.common_prefix_search(&text), standard);
+        }
+    }
+    #[test]
+    fn test_serialization() {
+        let trie = DoubleArrayTrie::from_sorted(&WORDS).unwrap();
+        let bytes = trie.to_bytes().unwrap();
+        let loaded = DoubleArrayTrie::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded, trie);
+        assert_eq!(loaded.get("bcd"), Some(4));
+        assert_eq!(DoubleArrayTrie::from_bytes(&bytes[..bytes.len() - 1]), Err(DoubleArrayError::Truncated));
+        assert_eq!(DoubleArrayTrie::from_bytes(&bytes[..6]), Err(DoubleArrayError::Truncated));
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert_eq!(DoubleArrayTrie::from_bytes(&extended), Err(DoubleArrayError::Corrupt));
+        let mut corrupt = bytes.clone();
+        corrupt[12..16].copy_from_slice(&i32::MAX.to_le_bytes());
+        assert_eq!(DoubleArrayTrie::from_bytes(&corrupt), Err(DoubleArrayError::Corrupt));
+        let empty = DoubleArrayTrie::from_sorted(&[]).unwrap();
+        assert_eq!(DoubleArrayTrie::from_bytes(&empty.to_bytes().unwrap()), Ok(empty));
+    }
+    #[test]
+    fn test_serialization_bound() {
+        // the words count is stored as u32, one past its maximum must not wrap to zero
+        let mut trie = DoubleArrayTrie::from_sorted(&WORDS).unwrap();
+        trie.words_count = u32::MAX as usize;
+        let bytes = trie.to_bytes().unwrap();
+        assert_eq!(bytes[..4], u32::MAX.to_le_bytes());
+        trie.words_count = u32::MAX as usize + 1;
+        assert_eq!(trie.to_bytes(), Err(DoubleArrayError::TooLarge));
+    }
+}
+
+#[cfg(test)]
+mod test_ternary_search_tree {
+    use std::collections::BTreeMap;
+    use crate::datastruct::tire::ternary_search_tree::TernarySearchTree;
+    use rand::{Rng, thread_rng};
+    const WORD1: &str = "hello";
+    const WORD2: &str = "world";
+    const WORD3: &str = "apple";
+    const WORD4: &str = "application";
+    const PREFIX1: &str = "app";
+    #[test]
+    fn test_word_api() {
+        let mut tree = TernarySearchTree::new();
+        assert!(!tree.query_word(WORD1));
+        tree.insert_word(WORD1);
+        assert!(tree.delete_word(WORD1));
+        assert_eq!(tree, TernarySearchTree::new());
+        for word in [WORD1, WORD2, WORD3, WORD4, PREFIX1] {
+            tree.insert_word(word);
+        }
+        let standard = tree.clone();
+        tree.insert_word(PREFIX1);
+        assert_ne!(tree, standard);
+        assert!(tree.delete_word(PREFIX1));
+        assert_eq!(tree, standard);
+        assert!(tree.query_word(PREFIX1));
+        assert!(!tree.query_word("ap"));
+        assert!(!tree.query_word("applications"));
+        assert_eq!(tree.query_prefix(PREFIX1), vec![PREFIX1, WORD3, WORD4]);
+        assert_eq!(tree.query_prefix("appl"), vec![WORD3, WORD4]);
+        assert_eq!(tree.query_prefix(""), vec![PREFIX1, WORD3, WORD4, WORD1, WORD2]);
+        assert_eq!(tree.query_prefix("b"), Vec::<String>::new());
+        assert!(tree.delete_word(PREFIX1));
+        assert!(!tree.delete_word(PREFIX1));
+        assert!(tree.query_word(WORD3));
+        for word in [WORD1, WORD2, WORD3, WORD4] {
+            assert!(tree.delete_word(word));
+        }
+        assert_eq!(tree, TernarySearchTree::new());
+    }
+    #[test]
+    fn test_search_hamming() {
+        let mut tree = TernarySearchTree::new();
+        for word in ["cat", "cot", "cut", "car", "bat", "dog", "cats"] {
+            tree.insert_word(word);
+        }
+        assert_eq!(tree.search_hamming("cat", 0), vec![("cat".to_string(), 0)]);
+        let expect: Vec<(String, usize)> = vec![("cat", 0), ("bat", 1), ("car", 1), ("cot", 1), ("cut", 1)]
+            .into_iter().map(|(word, distance)| (word.to_string(), distance)).collect();
+        assert_eq!(tree.search_hamming("cat", 1), expect);
+        assert_eq!(tree.search_hamming("dot", 1), vec![("cot".to_string(), 1), ("dog".to_string(), 1)]);
+        assert_eq!(tree.search_hamming("xyz", 2), vec![]);
+    }
+    #[test]
+    fn test_random() {
+        let mut tree = TernarySearchTree::new();
+        let mut standard: BTreeMap<String, usize> = BTreeMap::new();
+        for _ in 0..3000 {
+            let word: String = (0..thread_rng().gen_range(1..6)).map(|_| thread_rng().gen_range('a'..='d')).collect();
+            if thread_rng().gen_ratio(3, 5) {
+                tree.insert_word(&word);
+                *standard.entry(word).or_insert(0) += 1;
+            } else {
+                let expect = match standard.get_mut(&word) {
+                    Some(count) => {
+                        *count -= 1;
+                        if *count == 0 {
+                            standard.remove(&word);
+                        }
+                        true
+                    }
+                    None => false,
+                };
+                assert_eq!(tree.delete_word(&word), expect);
+            }
+            assert_eq!(tree.words_count, standard.values().sum());
+        }
+        let prefix: String = (0..2).map(|_| thread_rng().gen_range('a'..='d')).collect();
+        let expect: Vec<String> = standard.keys().filter(|word| word.starts_with(&prefix)).cloned().collect();
+        assert_eq!(tree.query_prefix(&prefix), expect);
+        let query: String = (0..4).map(|_| thread_rng().gen_range('a'..='d')).collect();
+        let mut expect: Vec<(String, usize)> = standard.keys()
+            .filter(|word| word.len() == query.len())
+            .map(|word| (word.clone(), word.chars().zip(query.chars()).filter(|(a, b)| a != b).count()))
+            .filter(|(_, distance)| *distance <= 2)
+            .collect();
+        expect.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+        assert_eq!(tree.search_hamming(&query, 2), expect);
+        let words: Vec<String> = standard.keys().cloned().collect();
+        for word in words {
+            while tree.delete_word(&word) {}
+            assert!(!tree.query_word(&word));
+        }
+        assert_eq!(tree, TernarySearchTree::new());
+    }
+}
+
+#[cfg(test)]
+mod test_trie_serialize {
+    use std::io::Read;
+    use crate::datastruct::tire::tire::Trie;
+    use crate::datastruct::tire::serialize::{TrieSerializeError, crc32_update};
+    use rand::{Rng, thread_rng};
+    fn sample() -> Trie {
+        let mut tire = Trie::new();
+        for word in ["hello", "world", "apple", "application", "app", "app", "caf\u{e9}"] {
+            tire.insert_word(word);
+        }
+        tire
+    }
+    #[test]
+    fn test_round_trip() {
+        assert_eq!(crc32_update(0, b"123456789"), 0xCBF4_3926);
+        let tire = sample();
+        let mut bytes = Vec::new();
+        tire.write_to(&mut bytes).unwrap();
+        assert_eq!(&bytes[..4], b"TRIE");
+        assert_eq!(Trie::read_from(bytes.as_slice()), Ok(tire));
+        let mut bytes = Vec::new();
+        Trie::new().write_to(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 14);
+        assert_eq!(Trie::read_from(bytes.as_slice()), Ok(Trie::new()));
+        let mut tire = Trie::new();
+        for _ in 0..2000 {
+            let word: String = (0..thread_rng().gen_range(1..8)).map(|_| thread_rng().gen_range('a'..='e')).collect();
+            tire.insert_word(&word);
+        }
+        let mut bytes = Vec::new();
+        tire.write_to(&mut bytes).unwrap();
+        bytes.extend(b"rest");
+        let mut reader = bytes.as_slice();
+        assert_eq!(Trie::read_from(&mut reader), Ok(tire));
+        let mut rest = String::new();
+        reader.read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, "rest");
+    }
+    #[test]
+    fn test_invalid_input() {
+        let mut bytes = Vec::new();
+        sample().write_to(&mut bytes).unwrap();
+        for len in 0..bytes.len() {
+            assert_eq!(Trie::read_from(&bytes[..len]), Err(TrieSerializeError::Truncated));
+        }
+        let mut corrupt = bytes.clone();
+        corrupt[0] = b'X';
+        assert_eq!(Trie::read_from(corrupt.as_slice()), Err(TrieSerializeError::BadMagic));
+        let mut corrupt = bytes.clone();
+        corrupt[4] = 2;
+        assert_eq!(Trie::read_from(corrupt.as_slice()), Err(TrieSerializeError::UnsupportedVersion(2)));
+        let mut corrupt = bytes.clone();
+        corrupt[10] ^= 1;
+        assert_eq!(Trie::read_from(corrupt.as_slice()), Err(TrieSerializeError::ChecksumMismatch));
+        let mut corrupt = bytes.clone();
+        let last = corrupt.len() - 1;
+        corrupt[last] ^= 0x80;
+        assert_eq!(Trie::read_from(corrupt.as_slice()), Err(TrieSerializeError::ChecksumMismatch));
+        // well formed and checksummed, but the words are out of order
+        let mut unsorted = b"TRIE\x01\x00\x02\x00\x00\x00".to_vec();
+        for word in ["b", "a"] {
+            unsorted.extend(1u32.to_le_bytes());
+            unsorted.extend(1u32.to_le_bytes());
+            unsorted.extend(word.as_bytes());
+        }
+        let crc = crc32_update(0, &unsorted);
+        unsorted.extend(crc.to_le_bytes());
+        assert_eq!(Trie::read_from(unsorted.as_slice()), Err(TrieSerializeError::Corrupt));
+        let mut invalid = b"TRIE\x01\x00\x01\x00\x00\x00\x01\x00\x00\x00\x01\x00\x00\x00\xff".to_vec();
+        invalid.extend(0u32.to_le_bytes());
+        assert_eq!(Trie::read_from(invalid.as_slice()), Err(TrieSerializeError::InvalidUtf8));
+    }
+    #[test]
+    fn test_count_too_large() {
+        let mut tire = sample();
+        tire.insert_word_times("many", u32::MAX as usize + 1);
+        let mut bytes = Vec::new();
+        assert_eq!(tire.write_to(&mut bytes), Err(TrieSerializeError::Io(std::io::ErrorKind::InvalidData)));
+        assert!(bytes.is_empty());
+        tire.delete_word("many");
+        bytes.clear();
+        tire.write_to(&mut bytes).unwrap();
+        assert_eq!(Trie::read_from(bytes.as_slice()).unwrap().words_count, tire.words_count);
+    }
 }
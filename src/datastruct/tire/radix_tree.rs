@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+// label is the edge leading into this node, children are keyed by the first char of their label
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadixNode {
+    pub label: String,
+    pub count: usize,
+    pub next: HashMap<char, Box<RadixNode>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadixTree {
+    pub words_count: usize,
+    pub root: RadixNode,
+}
+
+impl RadixNode {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            count: 0,
+            next: HashMap::new(),
+        }
+    }
+    fn collect_words(&self, prefix: &mut String, result: &mut Vec<String>) {
+        prefix.push_str(&self.label);
+        if self.count > 0 {
+            result.push(prefix.clone());
+        }
+        let mut children: Vec<(&char, &Box<RadixNode>)> = self.next.iter().collect();
+        children.sort_by(|a, b| a.0.cmp(b.0));
+        for (_, child) in children {
+            child.collect_words(prefix, result);
+        }
+        prefix.truncate(prefix.len() - self.label.len());
+    }
+}
+
+// length in bytes of the common prefix, always on a char boundary
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_, ca), cb)| ca != cb)
+        .map(|((index, _), _)| index)
+        .unwrap_or(usize::min(a.len(), b.len()))
+}
+
+impl RadixTree {
+    pub fn new() -> Self {
+        Self {
+            words_count: 0,
+            root: RadixNode::new(""),
+        }
+    }
+    pub fn insert_word(&mut self, word: &str) {
+        fn recursive_insert(node: &mut RadixNode, word: &str) {
+            let first_char = match word.chars().next() {
+                Some(c) => c,
+                None => {
+                    node.count += 1;
+                    return;
+                }
+            };
+            let child = match node.next.get_mut(&first_char) {
+                Some(child) => child,
+                None => {
+                    let mut leaf = RadixNode::new(word);
+                    leaf.count = 1;
+                    node.next.insert(first_char, Box::new(leaf));
+                    return;
+                }
+            };
+            let common = common_prefix_len(&child.label, word);
+            if common < child.label.len() {
+                // split the edge, the old child hangs below a new node holding the common part
+                let mut middle = Box::new(RadixNode::new(&child.label[..common]));
+                std::mem::swap(child, &mut middle);
+                middle.label = middle.label[common..].to_string();
+                let middle_first = middle.label.chars().next().expect("radix tree split empty label");
+                child.next.insert(middle_first, middle);
+            }
+            recursive_insert(child, &word[common..]);
+        }
+        recursive_insert(&mut self.root, word);
+        self.words_count += 1;
+    }
+    pub fn delete_word(&mut self, word: &str) -> bool {
+        fn recursive_delete(node: &mut RadixNode, word: &str) -> bool {
+            let first_char = match word.chars().next() {
+                Some(c) => c,
+                None => {
+                    if node.count == 0 {
+                        return false;
+                    }
+                    node.count -= 1;
+                    return true;
+                }
+            };
+            let child = match node.next.get_mut(&first_char) {
+                Some(child) if word.starts_with(&child.label) => child,
+                _ => return false,
+            };
+            let label_len = child.label.len();
+            if !recursive_delete(child, &word[label_len..]) {
+                return false;
+            }
+            if child.count == 0 {
+                match child.next.len() {
+                    0 => {
+                        node.next.remove(&first_char);
+                    }
+                    1 => {
+                        // re-merge a chain left with a single child
+                        let grandchild_char = *child.next.keys().next().expect("radix tree get only child error");
+                        let mut grandchild = child.next.remove(&grandchild_char).expect("radix tree get only child error");
+                        grandchild.label = format!("{}{}", child.label, grandchild.label);
+                        *child = grandchild;
+                    }
+                    _ => (),
+                }
+            }
+            true
+        }
+        let is_delete = recursive_delete(&mut self.root, word);
+        if is_delete {
+            self.words_count -= 1;
+        }
+        is_delete
+    }
+    pub fn query_word(&self, word: &str) -> bool {
+        let mut node = &self.root;
+        let mut word = word;
+        while let Some(c) = word.chars().next() {
+            match node.next.get(&c) {
+                Some(child) if word.starts_with(&child.label) => {
+                    word = &word[child.label.len()..];
+                    node = child;
+                }
+                _ => return false,
+            }
+        }
+        node.count > 0
+    }
+    // every word starting with prefix in lexicographic order
+    pub fn query_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut result = Vec::new();
+        let mut node = &self.root;
+        // labels of the nodes above node
+        let mut path = String::new();
+        let mut rest = prefix;
+        while let Some(c) = rest.chars().next() {
+            match node.next.get(&c) {
+                Some(child) if rest.starts_with(&child.label) => rest = &rest[child.label.len()..],
+                // the prefix ends in the middle of this edge
+                Some(child) if child.label.starts_with(rest) => rest = "",
+                _ => return result,
+            }
+            path.push_str(&node.label);
+            node = &node.next[&c];
+        }
+        node.collect_words(&mut path, &mut result);
+        result
+    }
+    // the longest inserted word that is a prefix of text
+    pub fn longest_prefix_match<'a>(&self, text: &'a str) -> Option<&'a str> {
+        let mut node = &self.root;
+        let mut matched = 0;
+        let mut best = if node.count > 0 {Some(0)} else {None};
+        while let Some(c) = text[matched..].chars().next() {
+            match node.next.get(&c) {
+                Some(child) if text[matched..].starts_with(&child.label) => {
+                    matched += child.label.len();
+                    node = child;
+                    if node.count > 0 {
+                        best = Some(matched);
+                    }
+                }
+                _ => break,
+            }
+        }
+        best.map(|len| &text[..len])
+    }
+}
+
+impl Default for RadixTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
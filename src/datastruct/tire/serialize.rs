@@ -0,0 +1,144 @@
+use std::io::{ErrorKind, Read, Write};
+
+use crate::datastruct::tire::tire::Trie;
+
+// binary layout, every integer little endian:
+//   magic      4 bytes "TRIE"
+//   version    u16, currently 1
+//   entries    u32, number of distinct words
+//   entries times, in increasing word order:
+//     count    u32, how many times the word was inserted
+//     length   u32, length of the word in bytes
+//     word     utf-8 bytes
+//   checksum   u32, crc-32 of every byte before it
+pub const TRIE_MAGIC: &[u8; 4] = b"TRIE";
+pub const TRIE_VERSION: u16 = 1;
+
+#[derive(Debug, PartialEq)]
+pub enum TrieSerializeError {
+    Io(ErrorKind),
+    Truncated,
+    BadMagic,
+    UnsupportedVersion(u16),
+    ChecksumMismatch,
+    InvalidUtf8,
+    Corrupt,
+}
+
+impl From<std::io::Error> for TrieSerializeError {
+    fn from(error: std::io::Error) -> Self {
+        match error.kind() {
+            ErrorKind::UnexpectedEof => TrieSerializeError::Truncated,
+            kind => TrieSerializeError::Io(kind),
+        }
+    }
+}
+
+// bitwise crc-32 with the reflected polynomial 0xEDB88320
+pub fn crc32_update(mut crc: u32, bytes: &[u8]) -> u32 {
+    crc = !crc;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {(crc >> 1) ^ 0xEDB8_8320} else {crc >> 1};
+        }
+    }
+    !crc
+}
+
+struct ChecksumWriter<W: Write> {
+    writer: W,
+    crc: u32,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), TrieSerializeError> {
+        self.crc = crc32_update(self.crc, bytes);
+        self.writer.write_all(bytes)?;
+        Ok(())
+    }
+}
+
+struct ChecksumReader<R: Read> {
+    reader: R,
+    crc: u32,
+}
+
+impl<R: Read> ChecksumReader<R> {
+    fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N], TrieSerializeError> {
+        let mut bytes = [0; N];
+        self.reader.read_exact(&mut bytes)?;
+        self.crc = crc32_update(self.crc, &bytes);
+        Ok(bytes)
+    }
+    fn read_u32(&mut self) -> Result<u32, TrieSerializeError> {
+        Ok(u32::from_le_bytes(self.read_bytes()?))
+    }
+    // reads through take so a corrupt length cannot allocate more than the input holds
+    fn read_vec(&mut self, len: usize) -> Result<Vec<u8>, TrieSerializeError> {
+        let mut bytes = Vec::new();
+        (&mut self.reader).take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() < len {
+            return Err(TrieSerializeError::Truncated);
+        }
+        self.crc = crc32_update(self.crc, &bytes);
+        Ok(bytes)
+    }
+}
+
+impl Trie {
+    pub fn write_to(&self, writer: impl Write) -> Result<(), TrieSerializeError> {
+        // every field must fit its u32, check them all before writing anything
+        let to_u32 = |n: usize| u32::try_from(n).map_err(|_| TrieSerializeError::Io(ErrorKind::InvalidData));
+        let mut words = Vec::new();
+        for (word, count) in self.iter() {
+            words.push((to_u32(count)?, to_u32(word.len())?, word));
+        }
+        let entries = to_u32(words.len())?;
+        let mut writer = ChecksumWriter {writer, crc: 0};
+        writer.write_all(TRIE_MAGIC)?;
+        writer.write_all(&TRIE_VERSION.to_le_bytes())?;
+        writer.write_all(&entries.to_le_bytes())?;
+        for (count, len, word) in words.iter() {
+            writer.write_all(&count.to_le_bytes())?;
+            writer.write_all(&len.to_le_bytes())?;
+            writer.write_all(word.as_bytes())?;
+        }
+        let crc = writer.crc;
+        writer.write_all(&crc.to_le_bytes())?;
+        writer.writer.flush()?;
+        Ok(())
+    }
+    // reads exactly one encoded trie, leaving anything after it in the reader
+    pub fn read_from(reader: impl Read) -> Result<Trie, TrieSerializeError> {
+        let mut reader = ChecksumReader {reader, crc: 0};
+        if &reader.read_bytes::<4>()? != TRIE_MAGIC {
+            return Err(TrieSerializeError::BadMagic);
+        }
+        let version = u16::from_le_bytes(reader.read_bytes()?);
+        if version != TRIE_VERSION {
+            return Err(TrieSerializeError::UnsupportedVersion(version));
+        }
+        let entries = reader.read_u32()?;
+        let mut words: Vec<(String, usize)> = Vec::new();
+        for _ in 0..entries {
+            let count = reader.read_u32()? as usize;
+            let len = reader.read_u32()? as usize;
+            let word = String::from_utf8(reader.read_vec(len)?).map_err(|_| TrieSerializeError::InvalidUtf8)?;
+            words.push((word, count));
+        }
+        let crc = reader.crc;
+        if reader.read_u32()? != crc {
+            return Err(TrieSerializeError::ChecksumMismatch);
+        }
+        let mut trie = Trie::new();
+        for i in 0..words.len() {
+            let (word, count) = &words[i];
+            if *count == 0 || word.is_empty() || (i > 0 && words[i - 1].0 >= *word) {
+                return Err(TrieSerializeError::Corrupt);
+            }
+            trie.insert_word_times(word, *count);
+        }
+        Ok(trie)
+    }
+}
@@ -0,0 +1,215 @@
+// left and right hold chars at the same position, mid continues the word
+#[derive(Debug, Clone, PartialEq)]
+pub struct TernaryNode {
+    pub c: char,
+    pub count: usize,
+    pub left: Option<Box<TernaryNode>>,
+    pub mid: Option<Box<TernaryNode>>,
+    pub right: Option<Box<TernaryNode>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TernarySearchTree {
+    pub words_count: usize,
+    pub root: Option<Box<TernaryNode>>,
+}
+
+impl TernaryNode {
+    pub fn new(c: char) -> Self {
+        Self {
+            c,
+            count: 0,
+            left: None,
+            mid: None,
+            right: None,
+        }
+    }
+    // in order, so words come out sorted
+    fn collect_words(&self, prefix: &mut String, result: &mut Vec<String>) {
+        if let Some(left) = self.left.as_ref() {
+            left.collect_words(prefix, result);
+        }
+        prefix.push(self.c);
+        if self.count > 0 {
+            result.push(prefix.clone());
+        }
+        if let Some(mid) = self.mid.as_ref() {
+            mid.collect_words(prefix, result);
+        }
+        prefix.pop();
+        if let Some(right) = self.right.as_ref() {
+            right.collect_words(prefix, result);
+        }
+    }
+}
+
+impl TernarySearchTree {
+    pub fn new() -> Self {
+        Self {
+            words_count: 0,
+            root: None,
+        }
+    }
+    fn find_node(&self, word: &[char]) -> Option<&TernaryNode> {
+        let mut node = self.root.as_deref()?;
+        let mut i = 0;
+        loop {
+            if word[i] < node.c {
+                node = node.left.as_deref()?;
+            } else if word[i] > node.c {
+                node = node.right.as_deref()?;
+            } else if i + 1 == word.len() {
+                return Some(node);
+            } else {
+                i += 1;
+                node = node.mid.as_deref()?;
+            }
+        }
+    }
+    pub fn insert_word(&mut self, word: &str) {
+        let word: Vec<char> = word.chars().collect();
+        if word.is_empty() {
+            return;
+        }
+        let mut node = &mut self.root;
+        let mut i = 0;
+        loop {
+            let current = node.get_or_insert_with(|| Box::new(TernaryNode::new(word[i])));
+            if word[i] < current.c {
+                node = &mut current.left;
+            } else if word[i] > current.c {
+                node = &mut current.right;
+            } else if i + 1 == word.len() {
+                current.count += 1;
+                break;
+            } else {
+                i += 1;
+                node = &mut current.mid;
+            }
+        }
+        self.words_count += 1;
+    }
+    pub fn delete_word(&mut self, word: &str) -> bool {
+        fn detach_min(node: &mut Option<Box<TernaryNode>>) -> Option<Box<TernaryNode>> {
+            if node.as_ref()?.left.is_some() {
+                return detach_min(&mut node.as_mut()?.left);
+            }
+            let mut min = node.take()?;
+            *node = min.right.take();
+            Some(min)
+        }
+        fn recursive_delete(node: &mut Option<Box<TernaryNode>>, word: &[char]) -> bool {
+            let current = match node.as_mut() {
+                Some(current) => current,
+                None => return false,
+            };
+            let is_delete = if word[0] < current.c {
+                recursive_delete(&mut current.left, word)
+            } else if word[0] > current.c {
+                recursive_delete(&mut current.right, word)
+            } else if word.len() == 1 {
+                if current.count == 0 {
+                    return false;
+                }
+                current.count -= 1;
+                true
+            } else {
+                recursive_delete(&mut current.mid, &word[1..])
+            };
+            // a node that ends no word and continues none is removed like a binary search tree node
+            if is_delete && current.count == 0 && current.mid.is_none() {
+                let mut removed = node.take().expect("ternary search tree get node error");
+                *node = match (removed.left.take(), removed.right.take()) {
+                    (None, right) => right,
+                    (left, None) => left,
+                    (left, mut right) => {
+                        let mut min = detach_min(&mut right).expect("ternary search tree get min node error");
+                        min.left = left;
+                        min.right = right;
+                        Some(min)
+                    }
+                };
+            }
+            is_delete
+        }
+        let word: Vec<char> = word.chars().collect();
+        if word.is_empty() {
+            return true;
+        }
+        let is_delete = recursive_delete(&mut self.root, &word);
+        if is_delete {
+            self.words_count -= 1;
+        }
+        is_delete
+    }
+    pub fn query_word(&self, word: &str) -> bool {
+        let word: Vec<char> = word.chars().collect();
+        if word.is_empty() {
+            return true;
+        }
+        self.find_node(&word).is_some_and(|node| node.count > 0)
+    }
+    // every word starting with prefix in lexicographic order
+    pub fn query_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut result = Vec::new();
+        let chars: Vec<char> = prefix.chars().collect();
+        if chars.is_empty() {
+            if let Some(root) = self.root.as_ref() {
+                root.collect_words(&mut String::new(), &mut result);
+            }
+            return result;
+        }
+        if let Some(node) = self.find_node(&chars) {
+            if node.count > 0 {
+                result.push(prefix.to_string());
+            }
+            if let Some(mid) = node.mid.as_ref() {
+                mid.collect_words(&mut prefix.to_string(), &mut result);
+            }
+        }
+        result
+    }
+    // words of the same length differing from word in at most max_distance positions, closest first
+    pub fn search_hamming(&self, word: &str, max_distance: usize) -> Vec<(String, usize)> {
+        fn recursive_search(node: Option<&TernaryNode>, word: &[char], distance: usize, max_distance: usize, prefix: &mut String, result: &mut Vec<(String, usize)>) {
+            let node = match node {
+                Some(node) => node,
+                None => return,
+            };
+            let spare = distance < max_distance;
+            if spare || word[0] < node.c {
+                recursive_search(node.left.as_deref(), word, distance, max_distance, prefix, result);
+            }
+            if spare || word[0] > node.c {
+                recursive_search(node.right.as_deref(), word, distance, max_distance, prefix, result);
+            }
+            let distance = distance + if word[0] == node.c {0} else {1};
+            if distance > max_distance {
+                return;
+            }
+            prefix.push(node.c);
+            if word.len() == 1 {
+                if node.count > 0 {
+                    result.push((prefix.clone(), distance));
+                }
+            } else {
+                recursive_search(node.mid.as_deref(), &word[1..], distance, max_distance, prefix, result);
+            }
+            prefix.pop();
+        }
+        let chars: Vec<char> = word.chars().collect();
+        let mut result = Vec::new();
+        if chars.is_empty() {
+            return result;
+        }
+        recursive_search(self.root.as_deref(), &chars, 0, max_distance, &mut String::new(), &mut result);
+        result.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+        result
+    }
+}
+
+impl Default for TernarySearchTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,5 +1,7 @@
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
 
+use crate::datastruct::heap::heap::Heap;
 use crate::node::TireNode;
 
 #[derive(Debug, Clone)]
@@ -23,6 +25,10 @@ impl Trie {
         }
     }
     pub fn insert_word(&mut self, word: &str) {
+        self.insert_word_times(word, 1);
+    }
+    pub(crate) fn insert_word_times(&mut self, word: &str, times: usize) {
+        let word_str = word;
         let mut word = word.chars();
         let mut node: &mut Box<TireNode<char>> = match word.next() {
             Some(first_char) => match self.next.get_mut(&first_char) {
@@ -42,8 +48,19 @@ impl Trie {
                 node = node.next.get_mut(&c).expect("tire tree get next node error");
             }
         }
-        node.count += 1;
-        self.words_count += 1;
+        node.count += times;
+        self.words_count += times;
+        // raise the subtree maxima along the path
+        let count = node.count;
+        let mut next = &mut self.next;
+        for c in word_str.chars() {
+            let node = next.get_mut(&c).expect("tire tree get next node error");
+            node.max = node.max.max(count);
+            next = &mut node.next;
+        }
+    }
+    fn update_max(node: &mut TireNode<char>) {
+        node.max = node.next.values().map(|next| next.max).fold(node.count, usize::max);
     }
     pub fn delete_word(&mut self, word: &str) -> bool {
         use std::str::Chars;
@@ -54,6 +71,7 @@ impl Trie {
                     Some(next) => {
                         let is_delete = recursive_delete_word(next, chars);
                         if is_delete {
+                            Trie::update_max(next);
                             if next.count == 0 && next.next.len() == 0 {
                                 node.next.remove(&c);
                             }                    
@@ -78,6 +96,7 @@ impl Trie {
                     Some(first_node) => {
                         let is_delete = recursive_delete_word(first_node, word);
                         if is_delete {
+                            Self::update_max(first_node);
                             if first_node.count == 0 && first_node.next.len() == 0 {
                                 self.next.remove(&first_char);
                             }
@@ -150,6 +169,169 @@ impl Trie {
         }
         result
     }
+    fn prefix_node(&self, prefix: &str) -> Option<&TireNode<char>> {
+        let mut prefix = prefix.chars();
+        let mut node = self.next.get(&prefix.next()?)?;
+        for c in prefix {
+            node = node.next.get(&c)?;
+        }
+        Some(node)
+    }
+    // words with their counts in lexicographic order
+    pub fn iter(&self) -> TrieIter<'_> {
+        let mut iter = TrieIter {stack: Vec::new()};
+        iter.push_children(String::new(), &self.next);
+        iter
+    }
+    pub fn iter_prefix(&self, prefix: &str) -> TrieIter<'_> {
+        if prefix.is_empty() {
+            return self.iter();
+        }
+        let mut iter = TrieIter {stack: Vec::new()};
+        if let Some(node) = self.prefix_node(prefix) {
+            iter.stack.push((prefix.to_string(), node));
+        }
+        iter
+    }
+    // the k most frequent completions, ties broken lexicographically
+    // best-first walk keyed by subtree maxima, so only subtrees that can still reach the top k are opened
+    pub fn top_k_with_prefix(&self, prefix: &str, k: usize) -> Vec<(String, usize)> {
+        let mut result = Vec::new();
+        let mut nodes: Vec<&TireNode<char>> = Vec::new();
+        // (count, word, is_word, node), a tie goes to the smaller word and a word comes before its own subtree
+        let mut heap = Heap::new();
+        heap.set_heap_order_max();
+        if prefix.is_empty() {
+            for (c, node) in self.next.iter() {
+                nodes.push(node);
+                heap.push((node.max, Reverse(c.to_string()), false, nodes.len() - 1));
+            }
+        } else if let Some(node) = self.prefix_node(prefix) {
+            nodes.push(node);
+            heap.push((node.max, Reverse(prefix.to_string()), false, 0));
+        }
+        while result.len() < k {
+            let (count, Reverse(word), is_word, slot) = match heap.pop() {
+                Some(entry) => entry,
+                None => break,
+            };
+            if is_word {
+                result.push((word, count));
+                continue;
+            }
+            let node = nodes[slot];
+            if node.count > 0 {
+                heap.push((node.count, Reverse(word.clone()), true, slot));
+            }
+            for (c, next) in node.next.iter() {
+                nodes.push(next);
+                heap.push((next.max, Reverse(format!("{}{}", word, c)), false, nodes.len() - 1));
+            }
+        }
+        result
+    }
+    // words within max_edits of word with their distance, closest first
+    pub fn search_within(&self, word: &str, max_edits: usize) -> Vec<(String, usize)> {
+        fn recursive_search(next: &HashMap<char, Box<TireNode<char>>>, word: &[char], prefix: &mut String, row: &[usize], max_edits: usize, result: &mut Vec<(String, usize)>) {
+            for (c, node) in next.iter() {
+                // row[i] is the distance between prefix and the first i chars of word
+                let mut new_row = Vec::with_capacity(row.len());
+                new_row.push(row[0] + 1);
+                for i in 1..row.len() {
+                    let replace = row[i - 1] + if word[i - 1] == *c {0} else {1};
+                    new_row.push(replace.min(row[i] + 1).min(new_row[i - 1] + 1));
+                }
+                prefix.push(*c);
+                let distance = new_row[word.len()];
+                if node.count > 0 && distance <= max_edits {
+                    result.push((prefix.clone(), distance));
+                }
+                if new_row.iter().min().is_some_and(|min| *min <= max_edits) {
+                    recursive_search(&node.next, word, prefix, &new_row, max_edits, result);
+                }
+                prefix.pop();
+            }
+        }
+        let word: Vec<char> = word.chars().collect();
+        let row: Vec<usize> = (0..=word.len()).collect();
+        let mut result = Vec::new();
+        recursive_search(&self.next, &word, &mut String::new(), &row, max_edits, &mut result);
+        result.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+        result
+    }
+    // '?' matches exactly one char and '*' any run of chars, results in lexicographic order
+    pub fn search_wildcard(&self, pattern: &str) -> Vec<String> {
+        type Next = HashMap<char, Box<TireNode<char>>>;
+        // a node is matched against the same rest of the pattern at most once, every '*' would otherwise multiply the work
+        fn recursive_search(count: usize, next: &Next, pattern: &[char], prefix: &mut String, visited: &mut HashSet<(*const Next, usize)>, result: &mut Vec<String>) {
+            if !visited.insert((next as *const Next, pattern.len())) {
+                return;
+            }
+            match pattern.first() {
+                None => if count > 0 {
+                    result.push(prefix.clone());
+                }
+                Some('*') => {
+                    recursive_search(count, next, &pattern[1..], prefix, visited, result);
+                    for (c, node) in next.iter() {
+                        prefix.push(*c);
+                        recursive_search(node.count, &node.next, pattern, prefix, visited, result);
+                        prefix.pop();
+                    }
+                }
+                Some('?') => for (c, node) in next.iter() {
+                    prefix.push(*c);
+                    recursive_search(node.count, &node.next, &pattern[1..], prefix, visited, result);
+                    prefix.pop();
+                }
+                Some(c) => if let Some(node) = next.get(c) {
+                    prefix.push(*c);
+                    recursive_search(node.count, &node.next, &pattern[1..], prefix, visited, result);
+                    prefix.pop();
+                }
+            }
+        }
+        let mut chars: Vec<char> = pattern.chars().collect();
+        chars.dedup_by(|a, b| *a == '*' && *b == '*');
+        let mut result = Vec::new();
+        recursive_search(0, &self.next, &chars, &mut String::new(), &mut HashSet::new(), &mut result);
+        result.sort();
+        result
+    }
 }
 
+impl Default for Trie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// pre-order walk with children pushed in reverse order, so a word comes before its extensions
+pub struct TrieIter<'a> {
+    pub stack: Vec<(String, &'a TireNode<char>)>,
+}
+
+impl<'a> TrieIter<'a> {
+    fn push_children(&mut self, prefix: String, next: &'a HashMap<char, Box<TireNode<char>>>) {
+        let mut children: Vec<(&char, &'a Box<TireNode<char>>)> = next.iter().collect();
+        children.sort_by(|a, b| b.0.cmp(a.0));
+        for (c, node) in children {
+            let mut word = prefix.clone();
+            word.push(*c);
+            self.stack.push((word, node));
+        }
+    }
+}
 
+impl<'a> Iterator for TrieIter<'a> {
+    type Item = (String, usize);
+    fn next(&mut self) -> Option<(String, usize)> {
+        while let Some((word, node)) = self.stack.pop() {
+            self.push_children(word.clone(), &node.next);
+            if node.count > 0 {
+                return Some((word, node.count));
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrieMapNode<K: Eq + Hash, V> {
+    pub value: Option<V>,
+    pub next: HashMap<K, Box<TrieMapNode<K, V>>>,
+}
+
+impl<K: Eq + Hash, V> TrieMapNode<K, V> {
+    pub fn new() -> Self {
+        Self {
+            value: None,
+            next: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> Default for TrieMapNode<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// the root stands for the empty key, so keys may be any sequence including an empty one
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrieMap<K: Eq + Hash, V> {
+    pub len: usize,
+    pub root: TrieMapNode<K, V>,
+}
+
+pub enum Entry<'a, K: Eq + Hash, V> {
+    Occupied(OccupiedEntry<'a, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+pub struct OccupiedEntry<'a, V> {
+    value: &'a mut V,
+}
+
+// node is the deepest node that already exists on the path, rest the part of the key below it
+pub struct VacantEntry<'a, K: Eq + Hash, V> {
+    node: &'a mut TrieMapNode<K, V>,
+    rest: Vec<K>,
+    len: &'a mut usize,
+}
+
+impl<K: Eq + Hash, V> TrieMap<K, V> {
+    pub fn new() -> Self {
+        Self {
+            len: 0,
+            root: TrieMapNode::new(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn get(&self, key: impl IntoIterator<Item = K>) -> Option<&V> {
+        let mut node = &self.root;
+        for k in key {
+            node = node.next.get(&k)?;
+        }
+        node.value.as_ref()
+    }
+    pub fn get_mut(&mut self, key: impl IntoIterator<Item = K>) -> Option<&mut V> {
+        let mut node = &mut self.root;
+        for k in key {
+            node = node.next.get_mut(&k)?;
+        }
+        node.value.as_mut()
+    }
+    pub fn contains_key(&self, key: impl IntoIterator<Item = K>) -> bool {
+        self.get(key).is_some()
+    }
+    pub fn insert(&mut self, key: impl IntoIterator<Item = K>, value: V) -> Option<V> {
+        let mut node = &mut self.root;
+        for k in key {
+            node = node.next.entry(k).or_default();
+        }
+        let old = node.value.replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+    pub fn remove(&mut self, key: impl IntoIterator<Item = K>) -> Option<V> {
+        // returns the removed value, nodes left without value and children are pruned on the way back
+        fn recursive_remove<K: Eq + Hash, V>(node: &mut TrieMapNode<K, V>, mut key: impl Iterator<Item = K>) -> Option<V> {
+            match key.next() {
+                Some(k) => {
+                    let next = node.next.get_mut(&k)?;
+                    let value = recursive_remove(next, key);
+                    if value.is_some() && next.value.is_none() && next.next.is_empty() {
+                        node.next.remove(&k);
+                    }
+                    value
+                }
+                None => node.value.take(),
+            }
+        }
+        let value = recursive_remove(&mut self.root, key.into_iter());
+        if value.is_some() {
+            self.len -= 1;
+        }
+        value
+    }
+    pub fn entry(&mut self, key: impl IntoIterator<Item = K>) -> Entry<'_, K, V> {
+        let mut key = key.into_iter();
+        let mut node = &mut self.root;
+        while let Some(k) = key.next() {
+            if node.next.contains_key(&k) {
+                node = node.next.get_mut(&k).expect("trie map get next node error");
+            } else {
+                let mut rest = vec![k];
+                rest.extend(key);
+                return Entry::Vacant(VacantEntry {node, rest, len: &mut self.len});
+            }
+        }
+        match node.value {
+            Some(ref mut value) => Entry::Occupied(OccupiedEntry {value}),
+            None => Entry::Vacant(VacantEntry {node, rest: Vec::new(), len: &mut self.len}),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> Default for TrieMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, K: Eq + Hash, V> Entry<'a, K, V> {
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+    pub fn or_default(self) -> &'a mut V where V: Default {
+        self.or_insert_with(V::default)
+    }
+    pub fn and_modify(self, f: impl FnOnce(&mut V)) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, V> OccupiedEntry<'a, V> {
+    pub fn get(&self) -> &V {
+        self.value
+    }
+    pub fn get_mut(&mut self) -> &mut V {
+        self.value
+    }
+    pub fn into_mut(self) -> &'a mut V {
+        self.value
+    }
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.value, value)
+    }
+}
+
+impl<'a, K: Eq + Hash, V> VacantEntry<'a, K, V> {
+    pub fn insert(self, value: V) -> &'a mut V {
+        let mut node = self.node;
+        for k in self.rest {
+            node = node.next.entry(k).or_default();
+        }
+        *self.len += 1;
+        node.value.insert(value)
+    }
+}
@@ -42,6 +42,8 @@ pub struct BTreeNode<T: PartialOrd + Default> {
 #[derive(Debug, Clone)]
 pub struct TireNode<T: Eq + Hash> {
     pub count: usize,
+    // the largest count in this subtree, itself included
+    pub max: usize,
     pub next: HashMap<T, Box<TireNode<T>>>,
 }
 
@@ -268,6 +270,7 @@ impl<T: Eq + Hash> TireNode<T> {
     pub fn new() -> Self {
         Self {
             count: 0,
+            max: 0,
             next: HashMap::new(),
         }
     }